@@ -0,0 +1,57 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use num_bigint::BigUint;
+use number_utils::gcd_big;
+
+/// Calculates greatest common divisor using Stein's algorithm, as `gcd_big` did before Lehmer's
+/// algorithm.
+fn stein_gcd(mut n: BigUint, mut m: BigUint) -> BigUint {
+    if n == BigUint::default() {
+        return m;
+    }
+    if m == BigUint::default() {
+        return n;
+    }
+    let gcd_exponent_on_two = (&n | &m).trailing_zeros().unwrap();
+    n >>= n.trailing_zeros().unwrap();
+    m >>= m.trailing_zeros().unwrap();
+    while n != m {
+        if n < m {
+            std::mem::swap(&mut n, &mut m);
+        }
+        n -= &m;
+        n >>= n.trailing_zeros().unwrap();
+    }
+    n << gcd_exponent_on_two
+}
+
+/// Generates a pseudorandom integer with the given number of decimal digits.
+fn random(digits: usize, state: &mut u64) -> BigUint {
+    let bytes = (0..digits * 10 / 24 + 1).map(|_| {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state as u8
+    });
+    BigUint::from_bytes_le(&bytes.collect::<Vec<_>>())
+}
+
+fn bench_gcd(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gcd");
+    group.sample_size(10);
+    let mut state = 0x2545_f491_4f6c_dd1d;
+    for digits in [1_000, 10_000, 100_000] {
+        let (a, b) = (random(digits, &mut state), random(digits, &mut state));
+        group.bench_with_input(
+            BenchmarkId::new("gcd_big", digits),
+            &digits,
+            |bencher, _| bencher.iter(|| gcd_big(a.clone(), b.clone())),
+        );
+        group.bench_with_input(BenchmarkId::new("stein", digits), &digits, |bencher, _| {
+            bencher.iter(|| stein_gcd(a.clone(), b.clone()))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_gcd);
+criterion_main!(benches);
@@ -1,5 +1,7 @@
-use num_bigint::{BigUint, ToBigUint};
+use crate::{lehmer, sqrt_continued_fraction, Convergents, Error, Rational};
+use num_bigint::{BigInt, BigUint, Sign, ToBigInt, ToBigUint};
 use num_iter::range_inclusive;
+use num_traits::{One, ToPrimitive, Zero};
 
 /// Calculates factorial.
 ///
@@ -94,7 +96,43 @@ pub fn combination_big<T: ToBigUint>(n: T, k: T) -> Option<BigUint> {
     }
 }
 
-/// Calculates greatest common divisor of two integers.
+/// Calculates generalized binomial coefficient `n(n - 1)...(n - k + 1) / k!`, which satisfies
+/// `C(n, k) = (-1)^k C(k - n - 1, k)` for negative n and is zero for negative k.
+///
+/// # Examples
+/// ```
+/// # use number_utils::binomial_bigint;
+/// # use num_bigint::ToBigInt;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(binomial_bigint(30, 15)?, 155_117_520.to_bigint()?);
+/// assert_eq!(binomial_bigint(-5, 3)?, (-35).to_bigint()?);
+/// assert_eq!(binomial_bigint(-2, 4)?, 5.to_bigint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn binomial_bigint<T: ToBigInt>(n: T, k: T) -> Option<BigInt> {
+    let (n_sign, n) = n.to_bigint()?.into_parts();
+    let (k_sign, k) = k.to_bigint()?.into_parts();
+    if k_sign == Sign::Minus {
+        return 0.to_bigint();
+    }
+    if n_sign != Sign::Minus {
+        return Some(BigInt::from(combination_big(n, k)?));
+    }
+    let odd = k.bit(0);
+    let binomial = BigInt::from(combination_big(n + &k - 1_u32, k)?);
+    if odd {
+        Some(-binomial)
+    } else {
+        Some(binomial)
+    }
+}
+
+/// Calculates greatest common divisor of two integers using Lehmer's algorithm, with half-gcd
+/// reductions for very large inputs.
 ///
 /// # Examples
 /// ```
@@ -109,26 +147,7 @@ pub fn combination_big<T: ToBigUint>(n: T, k: T) -> Option<BigUint> {
 /// # }
 /// ```
 pub fn gcd_big<T: ToBigUint>(n: T, m: T) -> Option<BigUint> {
-    let mut n = n.to_biguint()?;
-    let mut m = m.to_biguint()?;
-    let biguint_0 = 0.to_biguint()?;
-    if n == biguint_0 {
-        return Some(m);
-    }
-    if m == biguint_0 {
-        return Some(n);
-    }
-    let gcd_exponent_on_two = (&n | &m).trailing_zeros()?;
-    n >>= n.trailing_zeros()?;
-    m >>= m.trailing_zeros()?;
-    while n != m {
-        if n < m {
-            std::mem::swap(&mut n, &mut m);
-        }
-        n -= &m;
-        n >>= n.trailing_zeros()?;
-    }
-    Some(n << gcd_exponent_on_two)
+    Some(lehmer::gcd(n.to_biguint()?, m.to_biguint()?))
 }
 
 /// Calculates least common multiple.
@@ -150,3 +169,1716 @@ pub fn lcm_big<T: ToBigUint>(n: T, m: T) -> Option<BigUint> {
     let biguint_m = m.to_biguint()?;
     Some(biguint_n / gcd_big(n, m)? * biguint_m)
 }
+
+/// Calculates greatest common divisor of all integers, which is zero if there are none.
+///
+/// Stops reading the integers once the greatest common divisor reaches one.
+///
+/// # Examples
+/// ```
+/// # use number_utils::gcd_all_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(gcd_all_big([84, 120, 300])?, 12_u32.to_biguint()?);
+/// assert_eq!(gcd_all_big(Vec::<u32>::new())?, 0_u32.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn gcd_all_big<T: ToBigUint, I: IntoIterator<Item = T>>(ns: I) -> Option<BigUint> {
+    let mut gcd = BigUint::zero();
+    for n in ns {
+        gcd = lehmer::gcd(gcd, n.to_biguint()?);
+        if gcd.is_one() {
+            break;
+        }
+    }
+    Some(gcd)
+}
+
+/// Calculates least common multiple of all integers, which is one if there are none.
+///
+/// Stops reading the integers once the least common multiple reaches zero.
+///
+/// # Examples
+/// ```
+/// # use number_utils::lcm_all_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(lcm_all_big(1..=10)?, 2520_u32.to_biguint()?);
+/// assert_eq!(lcm_all_big([4, 0, 6])?, 0_u32.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn lcm_all_big<T: ToBigUint, I: IntoIterator<Item = T>>(ns: I) -> Option<BigUint> {
+    let mut lcm = BigUint::one();
+    for n in ns {
+        let n = n.to_biguint()?;
+        if n.is_zero() {
+            return Some(n);
+        }
+        lcm = &lcm / lehmer::gcd(lcm.clone(), n.clone()) * n;
+    }
+    Some(lcm)
+}
+
+/// Calculates greatest common divisor of signed integers, which is always non-negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::gcd_bigint;
+/// # use num_bigint::ToBigInt;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(gcd_bigint(-1247, 1073)?, 29.to_bigint()?);
+/// assert_eq!(gcd_bigint(0, -5)?, 5.to_bigint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn gcd_bigint<T: ToBigInt>(n: T, m: T) -> Option<BigInt> {
+    let n = n.to_bigint()?;
+    let m = m.to_bigint()?;
+    Some(BigInt::from(gcd_big(
+        n.magnitude().clone(),
+        m.magnitude().clone(),
+    )?))
+}
+
+/// Calculates least common multiple of signed integers, which is always non-negative and zero if
+/// either input is zero.
+///
+/// # Examples
+/// ```
+/// # use number_utils::lcm_bigint;
+/// # use num_bigint::ToBigInt;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(lcm_bigint(-120, 50)?, 600.to_bigint()?);
+/// assert_eq!(lcm_bigint(0, -5)?, 0.to_bigint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn lcm_bigint<T: ToBigInt>(n: T, m: T) -> Option<BigInt> {
+    let n = n.to_bigint()?;
+    let m = m.to_bigint()?;
+    if n.sign() == Sign::NoSign || m.sign() == Sign::NoSign {
+        return 0.to_bigint();
+    }
+    Some(BigInt::from(lcm_big(
+        n.magnitude().clone(),
+        m.magnitude().clone(),
+    )?))
+}
+
+/// Calculates `(g, x, y)` with `g = gcd(a, b)` and `ax + by = g` using the extended Euclidean
+/// algorithm. `g` is always non-negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::extended_gcd_bigint;
+/// # use num_bigint::ToBigInt;
+/// # fn calc() -> Option<()> {
+/// let (g, x, y) = extended_gcd_bigint(240, -46)?;
+/// assert_eq!(g, 2.to_bigint()?);
+/// assert_eq!(240 * x + -46 * y, g);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn extended_gcd_bigint<T: ToBigInt>(a: T, b: T) -> Option<(BigInt, BigInt, BigInt)> {
+    let (mut old_r, mut r) = (a.to_bigint()?, b.to_bigint()?);
+    let (mut old_x, mut x) = (1.to_bigint()?, 0.to_bigint()?);
+    let (mut old_y, mut y) = (0.to_bigint()?, 1.to_bigint()?);
+    while r.sign() != Sign::NoSign {
+        let q = &old_r / &r;
+        let next_r = &old_r - &q * &r;
+        let next_x = &old_x - &q * &x;
+        let next_y = &old_y - &q * &y;
+        (old_r, r) = (r, next_r);
+        (old_x, x) = (x, next_x);
+        (old_y, y) = (y, next_y);
+    }
+    if old_r.sign() == Sign::Minus {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    Some((old_r, old_x, old_y))
+}
+
+/// Calculates `(F(n), F(n + 1))` using fast doubling.
+fn fibonacci_pair_big(n: &BigUint) -> Option<(BigUint, BigUint)> {
+    let mut a = 0.to_biguint()?;
+    let mut b = 1.to_biguint()?;
+    for i in (0..n.bits()).rev() {
+        let c = &a * ((&b << 1) - &a);
+        let d = &a * &a + &b * &b;
+        (a, b) = if n.bit(i) {
+            let e = &c + &d;
+            (d, e)
+        } else {
+            (c, d)
+        };
+    }
+    Some((a, b))
+}
+
+/// Calculates n-th Fibonacci number using fast doubling.
+///
+/// # Examples
+/// ```
+/// # use number_utils::fibonacci_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(fibonacci_big(150)?, 9_969_216_677_189_303_386_214_405_760_200_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn fibonacci_big<T: ToBigUint>(n: T) -> Option<BigUint> {
+    let n = n.to_biguint()?;
+    let (a, b) = fibonacci_pair_big(&(&n >> 1))?;
+    if n.bit(0) {
+        Some(&a * &a + &b * &b)
+    } else {
+        Some(&a * ((&b << 1) - &a))
+    }
+}
+
+/// Calculates n-th Lucas number using fast doubling.
+///
+/// # Examples
+/// ```
+/// # use number_utils::lucas_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(lucas_big(150)?, 22_291_846_172_619_859_445_381_409_012_498_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn lucas_big<T: ToBigUint>(n: T) -> Option<BigUint> {
+    let (a, b) = fibonacci_pair_big(&n.to_biguint()?)?;
+    Some((b << 1) - a)
+}
+
+/// Calculates n-th Fibonacci number modulo `m` using fast doubling, returning None if `m` is zero.
+///
+/// # Examples
+/// ```
+/// # use number_utils::fibonacci_mod_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(fibonacci_mod_big(1_000_000_000_000_u64, 1_000_000_007)?, 730_695_249_u32.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn fibonacci_mod_big<T: ToBigUint>(n: T, m: T) -> Option<BigUint> {
+    let n = n.to_biguint()?;
+    let m = m.to_biguint()?;
+    if m == 0.to_biguint()? {
+        return None;
+    }
+    let mut a = 0.to_biguint()?;
+    let mut b = 1.to_biguint()? % &m;
+    for i in (0..n.bits()).rev() {
+        let c = &a * ((&b << 1) + &m - &a) % &m;
+        let d = (&a * &a + &b * &b) % &m;
+        (a, b) = if n.bit(i) {
+            let e = (&c + &d) % &m;
+            (d, e)
+        } else {
+            (c, d)
+        };
+    }
+    Some(a)
+}
+
+/// Calculates `T(n, k)` of a triangle satisfying `T(0, 0) = 1` and
+/// `T(i, j) = a(i, j) T(i - 1, j) + b(i, j) T(i - 1, j - 1)`.
+fn triangle_big(
+    n: usize,
+    k: usize,
+    a: impl Fn(usize, usize) -> usize,
+    b: impl Fn(usize, usize) -> usize,
+) -> Option<BigUint> {
+    if k > n {
+        return 0.to_biguint();
+    }
+    let width = k.min(n - k) + 2;
+    let mut row = vec![0.to_biguint()?; width];
+    row[0] = 1.to_biguint()?;
+    let (mut lo, mut hi) = (0, 0);
+    for i in 1..=n {
+        let (new_lo, new_hi) = ((k + i).saturating_sub(n), i.min(k));
+        for j in (new_lo..=new_hi).rev() {
+            let mut t = 0.to_biguint()?;
+            if lo <= j && j <= hi {
+                t += a(i, j) * &row[j % width];
+            }
+            if j > 0 && lo < j && j <= hi + 1 {
+                t += b(i, j) * &row[(j - 1) % width];
+            }
+            row[j % width] = t;
+        }
+        (lo, hi) = (new_lo, new_hi);
+    }
+    Some(row[k % width].clone())
+}
+
+/// Calculates n-th Catalan number.
+///
+/// # Examples
+/// ```
+/// # use number_utils::catalan_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(catalan_big(30)?, 3_814_986_502_092_304_u64.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn catalan_big<T: ToBigUint>(n: T) -> Option<BigUint> {
+    let n = n.to_biguint()?;
+    Some(combination_big(&n << 1, n.clone())? / (n + 1.to_biguint()?))
+}
+
+/// Calculates unsigned Stirling number of the first kind.
+///
+/// # Examples
+/// ```
+/// # use number_utils::stirling_first_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(stirling_first_big(30, 3)?, 62_262_192_842_035_613_491_057_459_200_000_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn stirling_first_big<T: ToBigUint>(n: T, k: T) -> Option<BigUint> {
+    let n = usize::try_from(n.to_biguint()?).ok()?;
+    let k = usize::try_from(k.to_biguint()?).ok()?;
+    triangle_big(n, k, |i, _| i - 1, |_, _| 1)
+}
+
+/// Calculates Stirling number of the second kind.
+///
+/// # Examples
+/// ```
+/// # use number_utils::stirling_second_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(stirling_second_big(30, 3)?, 34_314_651_811_530_u64.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn stirling_second_big<T: ToBigUint>(n: T, k: T) -> Option<BigUint> {
+    let n = usize::try_from(n.to_biguint()?).ok()?;
+    let k = usize::try_from(k.to_biguint()?).ok()?;
+    triangle_big(n, k, |_, j| j, |_, _| 1)
+}
+
+/// Calculates n-th Bell number using the Bell triangle.
+///
+/// # Examples
+/// ```
+/// # use number_utils::bell_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(bell_big(30)?, 846_749_014_511_809_332_450_147_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn bell_big<T: ToBigUint>(n: T) -> Option<BigUint> {
+    let n = usize::try_from(n.to_biguint()?).ok()?;
+    let mut row = vec![1.to_biguint()?];
+    for _ in 0..n {
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(row[row.len() - 1].clone());
+        for x in &row {
+            next.push(&next[next.len() - 1] + x);
+        }
+        row = next;
+    }
+    Some(row[0].clone())
+}
+
+/// Calculates unsigned Lah number.
+///
+/// # Examples
+/// ```
+/// # use number_utils::lah_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(lah_big(30, 3)?, 17_948_776_847_291_594_967_723_540_480_000_000_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn lah_big<T: ToBigUint>(n: T, k: T) -> Option<BigUint> {
+    let n = usize::try_from(n.to_biguint()?).ok()?;
+    let k = usize::try_from(k.to_biguint()?).ok()?;
+    triangle_big(n, k, |i, j| i - 1 + j, |_, _| 1)
+}
+
+/// Calculates Eulerian number.
+///
+/// # Examples
+/// ```
+/// # use number_utils::eulerian_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(eulerian_big(30, 3)?, 1_146_539_378_801_856_522_u64.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn eulerian_big<T: ToBigUint>(n: T, k: T) -> Option<BigUint> {
+    let n = usize::try_from(n.to_biguint()?).ok()?;
+    let k = usize::try_from(k.to_biguint()?).ok()?;
+    if n == 0 {
+        return if k == 0 { 1 } else { 0 }.to_biguint();
+    }
+    if k >= n {
+        return 0.to_biguint();
+    }
+    triangle_big(n, k.min(n - 1 - k), |_, j| j + 1, |i, j| i - j)
+}
+
+/// Calculates `a(n)` from
+/// `a(n) = c(n) + Σ (-1)^(k + 1) (a(n - k(3k - 1) / 2) + a(n - k(3k + 1) / 2))`.
+fn pentagonal_recurrence_big(n: usize, c: impl Fn(usize) -> i8) -> Option<BigUint> {
+    let mut table = vec![1.to_biguint()?];
+    for i in 1..=n {
+        let mut positive = 0.to_biguint()?;
+        let mut negative = 0.to_biguint()?;
+        match c(i) {
+            1 => positive += 1_u32,
+            -1 => negative += 1_u32,
+            _ => {}
+        }
+        for k in 1.. {
+            let (g1, g2) = (k * (3 * k - 1) / 2, k * (3 * k + 1) / 2);
+            if g1 > i {
+                break;
+            }
+            let sum = if (k & 1) == 1 {
+                &mut positive
+            } else {
+                &mut negative
+            };
+            *sum += &table[i - g1];
+            if g2 <= i {
+                *sum += &table[i - g2];
+            }
+        }
+        table.push(positive - negative);
+    }
+    table.pop()
+}
+
+/// Calculates the number of partitions p(n) using Euler's pentagonal number theorem.
+///
+/// # Examples
+/// ```
+/// # use number_utils::partitions_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(partitions_big(1000)?, 24_061_467_864_032_622_473_692_149_727_991_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn partitions_big<T: ToBigUint>(n: T) -> Option<BigUint> {
+    pentagonal_recurrence_big(usize::try_from(n.to_biguint()?).ok()?, |_| 0)
+}
+
+/// Calculates the number of partitions into distinct parts.
+///
+/// # Examples
+/// ```
+/// # use number_utils::distinct_partitions_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(distinct_partitions_big(1000)?, 8_635_565_795_744_155_161_506_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn distinct_partitions_big<T: ToBigUint>(n: T) -> Option<BigUint> {
+    pentagonal_recurrence_big(usize::try_from(n.to_biguint()?).ok()?, |i| {
+        if (i & 1) == 1 {
+            return 0;
+        }
+        for j in 1.. {
+            let (g1, g2) = (j * (3 * j - 1), j * (3 * j + 1));
+            if g1 > i {
+                break;
+            }
+            if g1 == i || g2 == i {
+                return if (j & 1) == 1 { -1 } else { 1 };
+            }
+        }
+        0
+    })
+}
+
+/// Calculates the number of partitions into at most k parts in O(nk) time.
+///
+/// # Examples
+/// ```
+/// # use number_utils::partitions_at_most_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(partitions_at_most_big(1000, 5)?, 357_746_987_u32.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn partitions_at_most_big<T: ToBigUint>(n: T, k: T) -> Option<BigUint> {
+    let n = usize::try_from(n.to_biguint()?).ok()?;
+    let k = usize::try_from(k.to_biguint()?.min(n.to_biguint()?)).ok()?;
+    let mut table = vec![0.to_biguint()?; n + 1];
+    table[0] = 1.to_biguint()?;
+    for part in 1..=k {
+        for i in part..=n {
+            let (head, tail) = table.split_at_mut(i);
+            tail[0] += &head[i - part];
+        }
+    }
+    table.pop()
+}
+
+/// Calculates the rank of a k-combination of `0..n` in lexicographic order using the
+/// combinatorial number system, returning None if the combination is not strictly increasing or
+/// contains an integer not less than n.
+///
+/// # Examples
+/// ```
+/// # use number_utils::rank_combination_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// let combination = (50..100).collect::<Vec<_>>();
+/// assert_eq!(rank_combination_big(&combination, 100)?, 100_891_344_545_564_193_334_812_497_255_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn rank_combination_big(combination: &[usize], n: usize) -> Option<BigUint> {
+    if !crate::ranking::is_combination(combination, n) {
+        return None;
+    }
+    let k = combination.len();
+    let mut rank = combination_big(n, k)? - 1.to_biguint()?;
+    for (i, &c) in combination.iter().enumerate() {
+        rank -= combination_big(n - 1 - c, k - i)?;
+    }
+    Some(rank)
+}
+
+/// Calculates the k-combination of `0..n` with the given rank in lexicographic order, returning
+/// None if the rank is out of range.
+///
+/// # Examples
+/// ```
+/// # use number_utils::unrank_combination_big;
+/// # fn main() {
+/// let combination = (50..100).collect::<Vec<_>>();
+/// assert_eq!(
+///     unrank_combination_big(100_891_344_545_564_193_334_812_497_255_u128, 100, 50),
+///     Some(combination)
+/// );
+/// # }
+/// ```
+pub fn unrank_combination_big<T: ToBigUint>(rank: T, n: usize, k: usize) -> Option<Vec<usize>> {
+    let count = combination_big(n, k)?;
+    let rank = rank.to_biguint()?;
+    if rank >= count {
+        return None;
+    }
+    let mut rest = count - 1.to_biguint()? - rank;
+    let mut combination = Vec::with_capacity(k);
+    if k == 0 {
+        return Some(combination);
+    }
+    // C(m, j) is updated incrementally while m and j decrease.
+    let (mut m, mut j) = (n - 1, k);
+    let mut binomial = combination_big(m, j)?;
+    loop {
+        while binomial > rest {
+            binomial = binomial * (m - j) / m;
+            m -= 1;
+        }
+        rest -= &binomial;
+        combination.push(n - 1 - m);
+        if j == 1 {
+            return Some(combination);
+        }
+        binomial = binomial * j / m;
+        m -= 1;
+        j -= 1;
+    }
+}
+
+/// Calculates the rank of a permutation of `0..n` in lexicographic order using the factorial
+/// number system, returning None if the slice is not a permutation.
+///
+/// # Examples
+/// ```
+/// # use number_utils::rank_permutation_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// let permutation = (0..30).rev().collect::<Vec<_>>();
+/// assert_eq!(rank_permutation_big(&permutation)?, 265_252_859_812_191_058_636_308_479_999_999_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn rank_permutation_big(permutation: &[usize]) -> Option<BigUint> {
+    let n = permutation.len();
+    let mut rank = 0.to_biguint()?;
+    for (i, d) in crate::ranking::lehmer_code(permutation)?
+        .into_iter()
+        .enumerate()
+    {
+        rank = rank * (n - i) + d;
+    }
+    Some(rank)
+}
+
+/// Calculates the permutation of `0..n` with the given rank in lexicographic order, returning
+/// None if the rank is out of range.
+///
+/// # Examples
+/// ```
+/// # use number_utils::unrank_permutation_big;
+/// # fn main() {
+/// let permutation = (0..30).rev().collect::<Vec<_>>();
+/// assert_eq!(
+///     unrank_permutation_big(265_252_859_812_191_058_636_308_479_999_999_u128, 30),
+///     Some(permutation)
+/// );
+/// # }
+/// ```
+pub fn unrank_permutation_big<T: ToBigUint>(rank: T, n: usize) -> Option<Vec<usize>> {
+    let mut rank = rank.to_biguint()?;
+    let mut code = vec![0; n];
+    for i in (0..n).rev() {
+        let base = (n - i).to_biguint()?;
+        code[i] = usize::try_from(&rank % &base).ok()?;
+        rank /= base;
+    }
+    if rank != 0.to_biguint()? {
+        return None;
+    }
+    Some(crate::ranking::from_lehmer_code(&code))
+}
+
+/// Calculates double factorial.
+///
+/// # Examples
+/// ```
+/// # use number_utils::double_factorial_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(double_factorial_big(41)?, 13_113_070_457_687_988_603_440_625_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn double_factorial_big<T: ToBigUint>(n: T) -> Option<BigUint> {
+    let n = n.to_biguint()?;
+    let mut double_factorial_big = 1.to_biguint()?;
+    let mut i = n;
+    let biguint_2 = 2.to_biguint()?;
+    while i >= biguint_2 {
+        double_factorial_big *= &i;
+        i -= &biguint_2;
+    }
+    Some(double_factorial_big)
+}
+
+/// Calculates multinomial coefficient (k1 + k2 + ...)! / (k1! k2! ...).
+///
+/// # Examples
+/// ```
+/// # use number_utils::multinomial_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(multinomial_big(&[10, 10, 10])?, 5_550_996_791_340_u64.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn multinomial_big<T: ToBigUint>(ks: &[T]) -> Option<BigUint> {
+    let mut multinomial_big = 1.to_biguint()?;
+    let mut sum = 0.to_biguint()?;
+    for k in ks {
+        let k = k.to_biguint()?;
+        sum += &k;
+        multinomial_big *= combination_big(sum.clone(), k)?;
+    }
+    Some(multinomial_big)
+}
+
+/// Calculates the number of derangements !n.
+///
+/// # Examples
+/// ```
+/// # use number_utils::derangement_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(derangement_big(30)?, 97_581_073_836_835_777_732_377_428_235_481_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn derangement_big<T: ToBigUint>(n: T) -> Option<BigUint> {
+    let n = usize::try_from(n.to_biguint()?).ok()?;
+    let mut a = 1.to_biguint()?;
+    let mut b = 0.to_biguint()?;
+    if n == 0 {
+        return Some(a);
+    }
+    for i in 2..=n {
+        let c = (i - 1) * (a + &b);
+        a = b;
+        b = c;
+    }
+    Some(b)
+}
+
+/// Calculates rencontres number D(n, k).
+///
+/// # Examples
+/// ```
+/// # use number_utils::rencontres_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(rencontres_big(30, 2)?, 48_790_536_918_417_888_866_188_714_117_755_u128.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn rencontres_big<T: ToBigUint>(n: T, k: T) -> Option<BigUint> {
+    let n = n.to_biguint()?;
+    let k = k.to_biguint()?;
+    if k > n {
+        return 0.to_biguint();
+    }
+    Some(combination_big(n.clone(), k.clone())? * derangement_big(n - k)?)
+}
+
+/// Calculates `(p, q)` with `p / q = 1/a + 1/(a + 1) + ... + 1/(b - 1)` by binary splitting,
+/// without reducing the fraction.
+fn harmonic_split(a: usize, b: usize) -> (BigUint, BigUint) {
+    if b - a == 1 {
+        return (BigUint::one(), BigUint::from(a));
+    }
+    let m = (a + b) / 2;
+    let (p1, q1) = harmonic_split(a, m);
+    let (p2, q2) = harmonic_split(m, b);
+    (p1 * &q2 + p2 * &q1, q1 * q2)
+}
+
+/// Calculates n-th harmonic number H(n) = 1 + 1/2 + ... + 1/n.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{harmonic_big, Rational};
+/// # fn calc() -> Option<()> {
+/// assert_eq!(harmonic_big(4)?, Rational::new(25, 12));
+/// assert_eq!(harmonic_big(0)?, Rational::new(0, 1));
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn harmonic_big<T: ToBigUint>(n: T) -> Option<Rational> {
+    let n = n.to_biguint()?.to_usize()?;
+    if n == 0 {
+        return Some(Rational::zero());
+    }
+    let (p, q) = harmonic_split(1, n + 1);
+    Some(Rational::new(p, q))
+}
+
+/// Calculates Bernoulli numbers B(0), ..., B(n) with B(1) = -1/2 using the recurrence
+/// `C(m + 1, 0) B(0) + C(m + 1, 1) B(1) + ... + C(m + 1, m) B(m) = 0`.
+fn bernoulli_numbers(n: usize) -> Vec<Rational> {
+    let mut bernoulli = vec![Rational::one()];
+    // C(m + 1, k) for k = 0..=m + 1.
+    let mut row = vec![BigInt::one(), BigInt::one()];
+    for m in 1..=n {
+        row = (0..=m + 1)
+            .map(|k| match k {
+                0 => BigInt::one(),
+                k if k == m + 1 => BigInt::one(),
+                k => &row[k - 1] + &row[k],
+            })
+            .collect();
+        if m > 1 && m % 2 == 1 {
+            bernoulli.push(Rational::zero());
+            continue;
+        }
+        let sum: Rational = (0..m)
+            .filter(|&k| !bernoulli[k].is_zero())
+            .map(|k| &bernoulli[k] * &Rational::from(row[k].clone()))
+            .sum();
+        bernoulli.push(-sum / Rational::from(row[m].clone()));
+    }
+    bernoulli
+}
+
+/// Calculates n-th Bernoulli number B(n) with the convention B(1) = -1/2.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{bernoulli_big, Rational};
+/// # fn calc() -> Option<()> {
+/// assert_eq!(bernoulli_big(1)?, Rational::new(-1, 2));
+/// assert_eq!(bernoulli_big(12)?, Rational::new(-691, 2730));
+/// assert_eq!(bernoulli_big(13)?, Rational::new(0, 1));
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn bernoulli_big<T: ToBigUint>(n: T) -> Option<Rational> {
+    let n = n.to_biguint()?.to_usize()?;
+    bernoulli_numbers(n).pop()
+}
+
+/// Calculates the coefficients of Faulhaber's polynomial `S(n) = 1^p + 2^p + ... + n^p`, where the
+/// i-th element is the coefficient of `n^i`.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{faulhaber_big, Rational};
+/// # fn calc() -> Option<()> {
+/// // 1^2 + 2^2 + ... + n^2 = n/6 + n^2/2 + n^3/3.
+/// assert_eq!(
+///     faulhaber_big(2)?,
+///     vec![
+///         Rational::new(0, 1),
+///         Rational::new(1, 6),
+///         Rational::new(1, 2),
+///         Rational::new(1, 3)
+///     ]
+/// );
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn faulhaber_big<T: ToBigUint>(p: T) -> Option<Vec<Rational>> {
+    let p = p.to_biguint()?.to_usize()?;
+    let mut bernoulli = bernoulli_numbers(p);
+    if p >= 1 {
+        // S(n) = (C(p + 1, 0) B(0) n^(p + 1) + ... + C(p + 1, p) B(p) n) / (p + 1) with B(1) = 1/2.
+        bernoulli[1] = Rational::new(1, 2);
+    }
+    let mut coefficients = vec![Rational::zero(); p + 2];
+    let mut binomial = BigUint::one();
+    for (j, b) in bernoulli.iter().enumerate() {
+        coefficients[p + 1 - j] = b * &Rational::new(binomial.clone(), p + 1);
+        binomial = binomial * (p + 1 - j) / (j + 1);
+    }
+    Some(coefficients)
+}
+
+/// Calculates the fundamental solution `(x, y)` of Pell's equation `x^2 - dy^2 = 1` from the
+/// continued fraction of `sqrt(d)`, returning None if `d` is a perfect square.
+///
+/// # Examples
+/// ```
+/// # use number_utils::pell_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(pell_big(2)?, (3_u32.to_biguint()?, 2_u32.to_biguint()?));
+/// assert_eq!(pell_big(61)?, (1_766_319_049_u32.to_biguint()?, 226_153_980_u32.to_biguint()?));
+/// assert_eq!(pell_big(16), None);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn pell_big<T: ToBigUint>(d: T) -> Option<(BigUint, BigUint)> {
+    let (a0, period) = sqrt_continued_fraction(d.to_biguint()?);
+    if period.is_empty() {
+        return None;
+    }
+    // The solution is the convergent before the end of the first period, or of the second if the
+    // period is odd.
+    let r = period.len();
+    let length = if r % 2 == 0 { r } else { 2 * r };
+    Convergents::new(std::iter::once(a0).chain(period.into_iter().cycle())).nth(length - 1)
+}
+
+/// Calculates number of digits in the given base, returning None if `base` is not in the range 2
+/// to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::digits_in_big;
+/// # fn main() {
+/// assert_eq!(digits_in_big(u128::MAX, 2), Some(128));
+/// assert_eq!(digits_in_big(u128::MAX, 10), Some(39));
+/// # }
+/// ```
+pub fn digits_in_big<T: ToBigUint>(n: T, base: u32) -> Option<usize> {
+    Some(to_digits_big(n, base)?.len())
+}
+
+/// Converts into digits in the given base, most significant digit first, returning None if
+/// `base` is not in the range 2 to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::to_digits_big;
+/// # fn main() {
+/// assert_eq!(to_digits_big(1234, 16), Some(vec![4, 13, 2]));
+/// # }
+/// ```
+pub fn to_digits_big<T: ToBigUint>(n: T, base: u32) -> Option<Vec<u8>> {
+    if !(2..=256).contains(&base) {
+        return None;
+    }
+    Some(n.to_biguint()?.to_radix_be(base))
+}
+
+/// Converts digits in the given base, most significant digit first, returning None if `base` is
+/// not in the range 2 to 256 or a digit is not less than `base`.
+///
+/// # Examples
+/// ```
+/// # use number_utils::from_digits_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(from_digits_big(&[4, 13, 2], 16)?, 1234.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn from_digits_big(digits: &[u8], base: u32) -> Option<BigUint> {
+    if !(2..=256).contains(&base) {
+        return None;
+    }
+    if digits.is_empty() {
+        return 0.to_biguint();
+    }
+    BigUint::from_radix_be(digits, base)
+}
+
+/// Calculates sum of digits in the given base, returning None if `base` is not in the range 2 to
+/// 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{digit_sum_big, factorial_big};
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(digit_sum_big(factorial_big(100)?, 10)?, 648.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn digit_sum_big<T: ToBigUint>(n: T, base: u32) -> Option<BigUint> {
+    let mut sum = 0.to_biguint()?;
+    for d in to_digits_big(n, base)? {
+        sum += d;
+    }
+    Some(sum)
+}
+
+/// Calculates digital root, the result of repeatedly summing digits in the given base until a
+/// single digit remains, returning None if `base` is not in the range 2 to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{digital_root_big, factorial_big};
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(digital_root_big(factorial_big(100)?, 10)?, 9.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn digital_root_big<T: ToBigUint>(n: T, base: u32) -> Option<BigUint> {
+    if !(2..=256).contains(&base) {
+        return None;
+    }
+    let n = n.to_biguint()?;
+    let biguint_1 = 1.to_biguint()?;
+    if n == 0.to_biguint()? {
+        Some(n)
+    } else {
+        Some((n - &biguint_1) % (base - 1) + biguint_1)
+    }
+}
+
+/// Reverses digits in the given base, returning None if `base` is not in the range 2 to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::reverse_digits_big;
+/// # use num_bigint::ToBigUint;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(reverse_digits_big(4_000_000_009_u64, 10)?, 9_000_000_004_u64.to_biguint()?);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn reverse_digits_big<T: ToBigUint>(n: T, base: u32) -> Option<BigUint> {
+    let mut digits = to_digits_big(n, base)?;
+    digits.reverse();
+    from_digits_big(&digits, base)
+}
+
+/// Determines whether digits in the given base read the same backward as forward, returning None
+/// if `base` is not in the range 2 to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::is_palindrome_big;
+/// # fn main() {
+/// assert_eq!(is_palindrome_big(12_345_678_987_654_321_u64, 10), Some(true));
+/// assert_eq!(is_palindrome_big(12_345_678_987_654_321_u64, 2), Some(false));
+/// # }
+/// ```
+pub fn is_palindrome_big<T: ToBigUint>(n: T, base: u32) -> Option<bool> {
+    let digits = to_digits_big(n, base)?;
+    Some(digits.iter().eq(digits.iter().rev()))
+}
+
+/// Converts into `BigUint`, returning an error if the input is negative.
+fn to_biguint<T: ToBigUint>(n: T) -> Result<BigUint, Error> {
+    n.to_biguint().ok_or(Error::NegativeInput)
+}
+
+/// Calculates factorial, returning an error if an input is negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_factorial_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_factorial_big(30)?, BigUint::from(265_252_859_812_191_058_636_308_480_000_000_u128));
+/// assert_eq!(try_factorial_big(-1), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_factorial_big<T: ToBigUint>(n: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    factorial_big(n).ok_or(Error::Overflow)
+}
+
+/// Calculates k-permutations, returning an error if an input is negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_permutation_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_permutation_big(30, 15)?, BigUint::from(202_843_204_931_727_360_000_u128));
+/// assert_eq!(try_permutation_big(30, -1), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_permutation_big<T: ToBigUint>(n: T, k: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let k = to_biguint(k)?;
+    permutation_big(n, k).ok_or(Error::Overflow)
+}
+
+/// Calculates k-combination, returning an error if an input is negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_combination_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_combination_big(30, 15)?, BigUint::from(155_117_520_u32));
+/// assert_eq!(try_combination_big(-30, 15), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_combination_big<T: ToBigUint>(n: T, k: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let k = to_biguint(k)?;
+    combination_big(n, k).ok_or(Error::Overflow)
+}
+
+/// Calculates greatest common divisor, returning an error if an input is negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_gcd_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_gcd_big(1247, 1073)?, BigUint::from(29_u32));
+/// assert_eq!(try_gcd_big(-1247, 1073), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_gcd_big<T: ToBigUint>(n: T, m: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let m = to_biguint(m)?;
+    Ok(lehmer::gcd(n, m))
+}
+
+/// Calculates least common multiple, returning an error if an input is negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_lcm_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_lcm_big(120, 50)?, BigUint::from(600_u32));
+/// assert_eq!(try_lcm_big(120, -50), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_lcm_big<T: ToBigUint>(n: T, m: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let m = to_biguint(m)?;
+    Ok(&n / lehmer::gcd(n.clone(), m.clone()) * m)
+}
+
+/// Calculates n-th Fibonacci number, returning an error if an input is negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_fibonacci_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_fibonacci_big(150)?, BigUint::from(9_969_216_677_189_303_386_214_405_760_200_u128));
+/// assert_eq!(try_fibonacci_big(-1), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_fibonacci_big<T: ToBigUint>(n: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    fibonacci_big(n).ok_or(Error::Overflow)
+}
+
+/// Calculates n-th Lucas number, returning an error if an input is negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_lucas_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_lucas_big(150)?, BigUint::from(22_291_846_172_619_859_445_381_409_012_498_u128));
+/// assert_eq!(try_lucas_big(-1), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_lucas_big<T: ToBigUint>(n: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    lucas_big(n).ok_or(Error::Overflow)
+}
+
+/// Calculates n-th Catalan number, returning an error if an input is negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_catalan_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_catalan_big(30)?, BigUint::from(3_814_986_502_092_304_u64));
+/// assert_eq!(try_catalan_big(-1), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_catalan_big<T: ToBigUint>(n: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    catalan_big(n).ok_or(Error::Overflow)
+}
+
+/// Calculates double factorial, returning an error if an input is negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_double_factorial_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_double_factorial_big(7)?, BigUint::from(105_u32));
+/// assert_eq!(try_double_factorial_big(-7), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_double_factorial_big<T: ToBigUint>(n: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    double_factorial_big(n).ok_or(Error::Overflow)
+}
+
+/// Calculates rencontres number D(n, k), returning an error if an input is negative or too large
+/// to index a table.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_rencontres_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_rencontres_big(5, 2)?, BigUint::from(20_u32));
+/// assert_eq!(try_rencontres_big(5, -2), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_rencontres_big<T: ToBigUint>(n: T, k: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let k = to_biguint(k)?;
+    rencontres_big(n, k).ok_or(Error::InvalidInput)
+}
+
+/// Calculates unsigned Stirling number of the first kind, returning an error if an input is
+/// negative or too large to index a table.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_stirling_first_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_stirling_first_big(5, 2)?, BigUint::from(50_u32));
+/// assert_eq!(try_stirling_first_big(-5, 2), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_stirling_first_big<T: ToBigUint>(n: T, k: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let k = to_biguint(k)?;
+    stirling_first_big(n, k).ok_or(Error::InvalidInput)
+}
+
+/// Calculates Stirling number of the second kind, returning an error if an input is negative or too
+/// large to index a table.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_stirling_second_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_stirling_second_big(5, 2)?, BigUint::from(15_u32));
+/// assert_eq!(try_stirling_second_big(-5, 2), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_stirling_second_big<T: ToBigUint>(n: T, k: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let k = to_biguint(k)?;
+    stirling_second_big(n, k).ok_or(Error::InvalidInput)
+}
+
+/// Calculates n-th Bell number, returning an error if an input is negative or too large to
+/// index a table.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_bell_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_bell_big(5)?, BigUint::from(52_u32));
+/// assert_eq!(try_bell_big(-5), Err(Error::NegativeInput));
+/// assert_eq!(try_bell_big(u128::MAX), Err(Error::InvalidInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_bell_big<T: ToBigUint>(n: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    bell_big(n).ok_or(Error::InvalidInput)
+}
+
+/// Calculates unsigned Lah number, returning an error if an input is negative or too large to
+/// index a table.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_lah_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_lah_big(5, 2)?, BigUint::from(240_u32));
+/// assert_eq!(try_lah_big(-5, 2), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_lah_big<T: ToBigUint>(n: T, k: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let k = to_biguint(k)?;
+    lah_big(n, k).ok_or(Error::InvalidInput)
+}
+
+/// Calculates Eulerian number, returning an error if an input is negative or too large to
+/// index a table.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_eulerian_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_eulerian_big(5, 2)?, BigUint::from(66_u32));
+/// assert_eq!(try_eulerian_big(-5, 2), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_eulerian_big<T: ToBigUint>(n: T, k: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let k = to_biguint(k)?;
+    eulerian_big(n, k).ok_or(Error::InvalidInput)
+}
+
+/// Calculates the number of partitions, returning an error if an input is negative or too large to
+/// index a table.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_partitions_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_partitions_big(100)?, BigUint::from(190_569_292_u32));
+/// assert_eq!(try_partitions_big(-100), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_partitions_big<T: ToBigUint>(n: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    partitions_big(n).ok_or(Error::InvalidInput)
+}
+
+/// Calculates the number of partitions into distinct parts, returning an error if an input is
+/// negative or too large to index a table.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_distinct_partitions_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_distinct_partitions_big(100)?, BigUint::from(444_793_u32));
+/// assert_eq!(try_distinct_partitions_big(-100), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_distinct_partitions_big<T: ToBigUint>(n: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    distinct_partitions_big(n).ok_or(Error::InvalidInput)
+}
+
+/// Calculates the number of partitions into at most k parts, returning an error if an input is
+/// negative or too large to index a table.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_partitions_at_most_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_partitions_at_most_big(10, 3)?, BigUint::from(14_u32));
+/// assert_eq!(try_partitions_at_most_big(10, -3), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_partitions_at_most_big<T: ToBigUint>(n: T, k: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let k = to_biguint(k)?;
+    partitions_at_most_big(n, k).ok_or(Error::InvalidInput)
+}
+
+/// Calculates the number of derangements, returning an error if an input is negative or too large
+/// to index a table.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_derangement_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_derangement_big(10)?, BigUint::from(1_334_961_u32));
+/// assert_eq!(try_derangement_big(-10), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_derangement_big<T: ToBigUint>(n: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    derangement_big(n).ok_or(Error::InvalidInput)
+}
+
+/// Calculates n-th Fibonacci number modulo `m`, returning an error if an input is negative or `m`
+/// is zero.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_fibonacci_mod_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_fibonacci_mod_big(1_000_000_000_000_u64, 1_000_000_007)?, BigUint::from(730_695_249_u32));
+/// assert_eq!(try_fibonacci_mod_big(10, 0), Err(Error::InvalidModulus));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_fibonacci_mod_big<T: ToBigUint>(n: T, m: T) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    let m = to_biguint(m)?;
+    fibonacci_mod_big(n, m).ok_or(Error::InvalidModulus)
+}
+
+/// Calculates multinomial coefficient (k1 + k2 + ...)! / (k1! k2! ...), returning an error if an
+/// input is negative.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_multinomial_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_multinomial_big(&[2, 3, 4])?, BigUint::from(1_260_u32));
+/// assert_eq!(try_multinomial_big(&[2, -3, 4]), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_multinomial_big<T: ToBigUint>(ks: &[T]) -> Result<BigUint, Error> {
+    let ks = ks
+        .iter()
+        .map(|k| k.to_biguint().ok_or(Error::NegativeInput))
+        .collect::<Result<Vec<_>, _>>()?;
+    multinomial_big(&ks).ok_or(Error::Overflow)
+}
+
+/// Calculates the rank of a k-combination of `0..n` in lexicographic order, returning an error if
+/// the combination is not strictly increasing or contains an integer not less than n.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_rank_combination_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_rank_combination_big(&[1, 3], 4)?, BigUint::from(4_u32));
+/// assert_eq!(try_rank_combination_big(&[3, 1], 4), Err(Error::InvalidInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_rank_combination_big(combination: &[usize], n: usize) -> Result<BigUint, Error> {
+    rank_combination_big(combination, n).ok_or(Error::InvalidInput)
+}
+
+/// Calculates the k-combination of `0..n` with the given rank in lexicographic order, returning
+/// an error if the rank is negative or not less than C(n, k).
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_unrank_combination_big, Error};
+/// # fn main() {
+/// assert_eq!(try_unrank_combination_big(4, 4, 2), Ok(vec![1, 3]));
+/// assert_eq!(try_unrank_combination_big(6, 4, 2), Err(Error::InvalidInput));
+/// assert_eq!(try_unrank_combination_big(-1, 4, 2), Err(Error::NegativeInput));
+/// # }
+/// ```
+pub fn try_unrank_combination_big<T: ToBigUint>(
+    rank: T,
+    n: usize,
+    k: usize,
+) -> Result<Vec<usize>, Error> {
+    unrank_combination_big(to_biguint(rank)?, n, k).ok_or(Error::InvalidInput)
+}
+
+/// Calculates the rank of a permutation of `0..n` in lexicographic order, returning an error if
+/// the slice is not a permutation of `0..n`.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_rank_permutation_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_rank_permutation_big(&[1, 0, 2])?, BigUint::from(2_u32));
+/// assert_eq!(try_rank_permutation_big(&[1, 1, 2]), Err(Error::InvalidInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_rank_permutation_big(permutation: &[usize]) -> Result<BigUint, Error> {
+    rank_permutation_big(permutation).ok_or(Error::InvalidInput)
+}
+
+/// Calculates the permutation of `0..n` with the given rank in lexicographic order, returning an
+/// error if the rank is negative or not less than n!.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_unrank_permutation_big, Error};
+/// # fn main() {
+/// assert_eq!(try_unrank_permutation_big(2, 3), Ok(vec![1, 0, 2]));
+/// assert_eq!(try_unrank_permutation_big(6, 3), Err(Error::InvalidInput));
+/// # }
+/// ```
+pub fn try_unrank_permutation_big<T: ToBigUint>(rank: T, n: usize) -> Result<Vec<usize>, Error> {
+    unrank_permutation_big(to_biguint(rank)?, n).ok_or(Error::InvalidInput)
+}
+
+/// Checks that `base` is in the range 2 to 256.
+fn check_base(base: u32) -> Result<(), Error> {
+    if (2..=256).contains(&base) {
+        Ok(())
+    } else {
+        Err(Error::InvalidBase)
+    }
+}
+
+/// Calculates number of digits in the given base, returning an error if `n` is negative or `base`
+/// is not in the range 2 to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_digits_in_big, Error};
+/// # fn main() {
+/// assert_eq!(try_digits_in_big(255, 16), Ok(2));
+/// assert_eq!(try_digits_in_big(255, 1), Err(Error::InvalidBase));
+/// # }
+/// ```
+pub fn try_digits_in_big<T: ToBigUint>(n: T, base: u32) -> Result<usize, Error> {
+    Ok(try_to_digits_big(n, base)?.len())
+}
+
+/// Converts into digits in the given base, most significant digit first, returning an error if
+/// `n` is negative or `base` is not in the range 2 to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_to_digits_big, Error};
+/// # fn main() {
+/// assert_eq!(try_to_digits_big(1234, 16), Ok(vec![4, 13, 2]));
+/// assert_eq!(try_to_digits_big(-1234, 16), Err(Error::NegativeInput));
+/// # }
+/// ```
+pub fn try_to_digits_big<T: ToBigUint>(n: T, base: u32) -> Result<Vec<u8>, Error> {
+    let n = to_biguint(n)?;
+    check_base(base)?;
+    to_digits_big(n, base).ok_or(Error::InvalidBase)
+}
+
+/// Converts digits in the given base, most significant digit first, returning an error if `base`
+/// is not in the range 2 to 256 or a digit is not less than `base`.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_from_digits_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_from_digits_big(&[4, 13, 2], 16)?, BigUint::from(1234_u32));
+/// assert_eq!(try_from_digits_big(&[1, 2], 2), Err(Error::InvalidInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_from_digits_big(digits: &[u8], base: u32) -> Result<BigUint, Error> {
+    check_base(base)?;
+    from_digits_big(digits, base).ok_or(Error::InvalidInput)
+}
+
+/// Calculates the sum of digits in the given base, returning an error if `n` is negative or
+/// `base` is not in the range 2 to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_digit_sum_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_digit_sum_big(1234, 10)?, BigUint::from(10_u32));
+/// assert_eq!(try_digit_sum_big(1234, 0), Err(Error::InvalidBase));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_digit_sum_big<T: ToBigUint>(n: T, base: u32) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    check_base(base)?;
+    digit_sum_big(n, base).ok_or(Error::InvalidBase)
+}
+
+/// Calculates the digital root in the given base, returning an error if `n` is negative or `base`
+/// is not in the range 2 to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_digital_root_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_digital_root_big(1234, 10)?, BigUint::from(1_u32));
+/// assert_eq!(try_digital_root_big(1234, 300), Err(Error::InvalidBase));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_digital_root_big<T: ToBigUint>(n: T, base: u32) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    check_base(base)?;
+    digital_root_big(n, base).ok_or(Error::InvalidBase)
+}
+
+/// Reverses digits in the given base, returning an error if `n` is negative or `base` is not in
+/// the range 2 to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_reverse_digits_big, Error};
+/// # use num_bigint::BigUint;
+/// # fn calc() -> Result<(), Error> {
+/// assert_eq!(try_reverse_digits_big(1234, 10)?, BigUint::from(4321_u32));
+/// assert_eq!(try_reverse_digits_big(-1234, 10), Err(Error::NegativeInput));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn try_reverse_digits_big<T: ToBigUint>(n: T, base: u32) -> Result<BigUint, Error> {
+    let n = to_biguint(n)?;
+    check_base(base)?;
+    reverse_digits_big(n, base).ok_or(Error::InvalidBase)
+}
+
+/// Checks if the number is a palindrome in the given base, returning an error if `n` is negative
+/// or `base` is not in the range 2 to 256.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_is_palindrome_big, Error};
+/// # fn main() {
+/// assert_eq!(try_is_palindrome_big(12321, 10), Ok(true));
+/// assert_eq!(try_is_palindrome_big(12321, 1), Err(Error::InvalidBase));
+/// # }
+/// ```
+pub fn try_is_palindrome_big<T: ToBigUint>(n: T, base: u32) -> Result<bool, Error> {
+    let n = to_biguint(n)?;
+    check_base(base)?;
+    is_palindrome_big(n, base).ok_or(Error::InvalidBase)
+}
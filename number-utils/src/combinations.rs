@@ -0,0 +1,94 @@
+/// An iterator over the k-combinations of a slice in lexicographic order of positions.
+///
+/// # Examples
+/// ```
+/// # use number_utils::Combinations;
+/// # fn main() {
+/// assert_eq!(
+///     Combinations::new(&[1, 2, 3, 4], 2).collect::<Vec<_>>(),
+///     vec![vec![1, 2], vec![1, 3], vec![1, 4], vec![2, 3], vec![2, 4], vec![3, 4]]
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Combinations<T> {
+    pool: Vec<T>,
+    indices: Option<Vec<usize>>,
+}
+
+impl<T: Clone> Combinations<T> {
+    /// Creates a new iterator over the k-combinations of the slice.
+    pub fn new(pool: &[T], k: usize) -> Self {
+        Self {
+            pool: pool.to_vec(),
+            indices: (k <= pool.len()).then(|| (0..k).collect()),
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let combination = indices.iter().map(|&i| self.pool[i].clone()).collect();
+        let (n, k) = (self.pool.len(), indices.len());
+        match (0..k).rev().find(|&i| indices[i] != i + n - k) {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..k {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => self.indices = None,
+        }
+        Some(combination)
+    }
+}
+
+/// An iterator over the k-combinations with repetition of a slice in lexicographic order of
+/// positions.
+///
+/// # Examples
+/// ```
+/// # use number_utils::CombinationsWithRepetition;
+/// # fn main() {
+/// assert_eq!(
+///     CombinationsWithRepetition::new(&[1, 2, 3], 2).collect::<Vec<_>>(),
+///     vec![vec![1, 1], vec![1, 2], vec![1, 3], vec![2, 2], vec![2, 3], vec![3, 3]]
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CombinationsWithRepetition<T> {
+    pool: Vec<T>,
+    indices: Option<Vec<usize>>,
+}
+
+impl<T: Clone> CombinationsWithRepetition<T> {
+    /// Creates a new iterator over the k-combinations with repetition of the slice.
+    pub fn new(pool: &[T], k: usize) -> Self {
+        Self {
+            pool: pool.to_vec(),
+            indices: (k == 0 || !pool.is_empty()).then(|| vec![0; k]),
+        }
+    }
+}
+
+impl<T: Clone> Iterator for CombinationsWithRepetition<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let combination = indices.iter().map(|&i| self.pool[i].clone()).collect();
+        let n = self.pool.len();
+        match (0..indices.len()).rev().find(|&i| indices[i] != n - 1) {
+            Some(i) => {
+                let index = indices[i] + 1;
+                indices[i..].fill(index);
+            }
+            None => self.indices = None,
+        }
+        Some(combination)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{NumberUtils, UnsignedInteger};
+
+/// Calculates `x * c`, returning None if overflow occured.
+///
+/// `c` only has to fit in `T` when `x` is not zero.
+pub(crate) fn checked_mul_usize<T: UnsignedInteger>(x: &T, c: usize) -> Option<T> {
+    if x.is_zero() {
+        return Some(T::zero());
+    }
+    x.checked_mul(&T::from_usize(c)?)
+}
+
+/// Calculates `T(n, k)` of a triangle satisfying `T(0, 0) = 1` and
+/// `T(i, j) = a(i, j) T(i - 1, j) + b(i, j) T(i - 1, j - 1)`, returning None if overflow occured.
+///
+/// Only the `min(k, n - k) + 1` columns that can reach `T(n, k)` are kept, so every computed
+/// value is at most `T(n, k)` as long as the coefficients are positive.
+pub(crate) fn checked_triangle<T: UnsignedInteger>(
+    n: usize,
+    k: usize,
+    a: impl Fn(usize, usize) -> usize,
+    b: impl Fn(usize, usize) -> usize,
+) -> Option<T> {
+    if k > n {
+        return Some(T::zero());
+    }
+    let width = k.min(n - k) + 2;
+    let mut row = vec![T::zero(); width];
+    row[0] = T::one();
+    let (mut lo, mut hi) = (0, 0);
+    for i in 1..=n {
+        let (new_lo, new_hi) = ((k + i).saturating_sub(n), i.min(k));
+        let get = |row: &[T], j: usize| {
+            if lo <= j && j <= hi {
+                row[j % width].clone()
+            } else {
+                T::zero()
+            }
+        };
+        for j in (new_lo..=new_hi).rev() {
+            let t = checked_mul_usize(&get(&row, j), a(i, j))?;
+            let u = if j == 0 {
+                T::zero()
+            } else {
+                checked_mul_usize(&get(&row, j - 1), b(i, j))?
+            };
+            row[j % width] = t.checked_add(&u)?;
+        }
+        (lo, hi) = (new_lo, new_hi);
+    }
+    Some(row[k % width].clone())
+}
+
+/// Checks whether `n` equals `k`.
+fn eq_usize<T: UnsignedInteger>(n: &T, k: usize) -> bool {
+    n.to_usize() == Some(k)
+}
+
+/// Calculates n-th Catalan number, returning None if overflow occured.
+pub(crate) fn checked_catalan<T: UnsignedInteger>(n: &T) -> Option<T> {
+    let mut catalan = T::one();
+    for i in 0..n.to_usize()? {
+        // C(i + 1) = C(i) * 2(2i + 1) / (i + 2), divided first to avoid overflow.
+        let g = NumberUtils::gcd(&catalan, T::from_usize(i + 2)?);
+        let factor = (4 * i + 2) / ((i + 2) / g.to_usize()?);
+        catalan = checked_mul_usize(&(catalan / g), factor)?;
+    }
+    Some(catalan)
+}
+
+/// Calculates unsigned Stirling number of the first kind, returning None if overflow occured.
+pub(crate) fn checked_stirling_first<T: UnsignedInteger>(n: &T, k: usize) -> Option<T> {
+    if eq_usize(n, k) {
+        return Some(T::one());
+    }
+    checked_triangle(n.to_usize()?, k, |i, _| i - 1, |_, _| 1)
+}
+
+/// Calculates Stirling number of the second kind, returning None if overflow occured.
+pub(crate) fn checked_stirling_second<T: UnsignedInteger>(n: &T, k: usize) -> Option<T> {
+    if eq_usize(n, k) || (!n.is_zero() && k == 1) {
+        return Some(T::one());
+    }
+    checked_triangle(n.to_usize()?, k, |_, j| j, |_, _| 1)
+}
+
+/// Calculates n-th Bell number using the Bell triangle, returning None if overflow occured.
+///
+/// The row ending with `B(n)` is the last one built, so every computed value is at most `B(n)`.
+pub(crate) fn checked_bell<T: UnsignedInteger>(n: &T) -> Option<T> {
+    if n.is_zero() {
+        return Some(T::one());
+    }
+    let mut row = vec![T::one()];
+    for _ in 1..n.to_usize()? {
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(row[row.len() - 1].clone());
+        for x in &row {
+            next.push(next[next.len() - 1].checked_add(x)?);
+        }
+        row = next;
+    }
+    row.pop()
+}
+
+/// Calculates unsigned Lah number, returning None if overflow occured.
+pub(crate) fn checked_lah<T: UnsignedInteger>(n: &T, k: usize) -> Option<T> {
+    if eq_usize(n, k) {
+        return Some(T::one());
+    }
+    checked_triangle(n.to_usize()?, k, |i, j| i - 1 + j, |_, _| 1)
+}
+
+/// Calculates Eulerian number, returning None if overflow occured.
+pub(crate) fn checked_eulerian<T: UnsignedInteger>(n: &T, k: usize) -> Option<T> {
+    if n.is_zero() {
+        return Some(if k == 0 { T::one() } else { T::zero() });
+    }
+    if k == 0 {
+        return Some(T::one());
+    }
+    let n = n.to_usize()?;
+    if k >= n {
+        return Some(T::zero());
+    }
+    // A(n, k) = A(n, n - 1 - k).
+    let k = k.min(n - 1 - k);
+    if k == 0 {
+        return Some(T::one());
+    }
+    checked_triangle(n, k, |_, j| j + 1, |i, j| i - j)
+}
+
+/// Calculates `x * a / b` for `b` dividing `x * a`, dividing first to avoid overflow, returning
+/// None if overflow occured.
+fn checked_mul_div<T: UnsignedInteger>(x: T, a: T, b: T) -> Option<T> {
+    let g = NumberUtils::gcd(&x, b.clone());
+    (x / g.clone()).checked_mul(&(a / (b / g)))
+}
+
+/// Calculates k-combination without intermediate overflow, returning None if overflow occured.
+pub(crate) fn checked_binomial<T: UnsignedInteger>(n: &T, k: &T) -> Option<T> {
+    if k > n {
+        return Some(T::zero());
+    }
+    let n_k = n.clone() - k.clone();
+    let k = if k < &n_k { k.clone() } else { n_k.clone() };
+    let n_k = n.clone() - k.clone();
+    let mut binomial = T::one();
+    let mut j = T::zero();
+    while j < k {
+        // C(n - k + j + 1, j + 1) = C(n - k + j, j) * (n - k + j + 1) / (j + 1).
+        let j_1 = j + T::one();
+        binomial = checked_mul_div(binomial, n_k.clone() + j_1.clone(), j_1.clone())?;
+        j = j_1;
+    }
+    Some(binomial)
+}
+
+/// Calculates multinomial coefficient, returning None if overflow occured.
+pub(crate) fn checked_multinomial<T: UnsignedInteger>(ks: &[T]) -> Option<T> {
+    let mut multinomial = T::one();
+    let mut sum = T::zero();
+    for k in ks {
+        sum = sum.checked_add(k)?;
+        multinomial = multinomial.checked_mul(&checked_binomial(&sum, k)?)?;
+    }
+    Some(multinomial)
+}
+
+/// Calculates the number of derangements !n, returning None if overflow occured.
+pub(crate) fn checked_derangement<T: UnsignedInteger>(n: &T) -> Option<T> {
+    let (mut a, mut b) = (T::one(), T::zero());
+    if n.is_zero() {
+        return Some(a);
+    }
+    for i in 2..=n.to_usize()? {
+        // !i = (i - 1)(!(i - 1) + !(i - 2)).
+        let c = checked_mul_usize(&a.checked_add(&b)?, i - 1)?;
+        (a, b) = (b, c);
+    }
+    Some(b)
+}
+
+/// Calculates rencontres number, returning None if overflow occured.
+pub(crate) fn checked_rencontres<T: UnsignedInteger>(n: &T, k: &T) -> Option<T> {
+    if k > n || (n.clone() - k.clone()).is_one() {
+        return Some(T::zero());
+    }
+    checked_binomial(n, k)?.checked_mul(&checked_derangement(&(n.clone() - k.clone()))?)
+}
@@ -0,0 +1,201 @@
+use crate::UnsignedInteger;
+use num_integer::Roots;
+use std::cmp::Ordering;
+
+/// Calculates the continued fraction `[a0; a1, ..., ak]` of `p / q` using Euclid's algorithm.
+///
+/// # Panics
+/// Panics if `q` is zero.
+///
+/// # Examples
+/// ```
+/// # use number_utils::continued_fraction;
+/// # fn main() {
+/// assert_eq!(continued_fraction(415_u32, 93), vec![4, 2, 6, 7]);
+/// # }
+/// ```
+pub fn continued_fraction<T: UnsignedInteger>(p: T, q: T) -> Vec<T> {
+    assert!(!q.is_zero(), "denominator must not be zero");
+    let (mut p, mut q) = (p, q);
+    let mut terms = vec![];
+    while !q.is_zero() {
+        let (a, r) = p.div_rem(&q);
+        terms.push(a);
+        (p, q) = (q, r);
+    }
+    terms
+}
+
+/// Calculates the continued fraction `[a0; (a1, ..., ar)]` of `sqrt(n)` as `a0` and the period
+/// `(a1, ..., ar)`, which is empty if `n` is a perfect square.
+///
+/// # Examples
+/// ```
+/// # use number_utils::sqrt_continued_fraction;
+/// # fn main() {
+/// assert_eq!(sqrt_continued_fraction(23_u32), (4, vec![1, 3, 1, 8]));
+/// assert_eq!(sqrt_continued_fraction(25_u32), (5, vec![]));
+/// # }
+/// ```
+pub fn sqrt_continued_fraction<T: UnsignedInteger + Roots>(n: T) -> (T, Vec<T>) {
+    let a0 = n.sqrt();
+    let mut period = vec![];
+    if a0.clone() * a0.clone() == n {
+        return (a0, period);
+    }
+    // sqrt(n) = [a0; a1, ..., ai, (sqrt(n) + m) / d], where every value stays below 2 sqrt(n).
+    let (mut m, mut d, mut a) = (T::zero(), T::one(), a0.clone());
+    while a != a0.clone() + a0.clone() {
+        m = d.clone() * a - m;
+        d = (n.clone() - m.clone() * m.clone()) / d;
+        a = (a0.clone() + m.clone()) / d.clone();
+        period.push(a.clone());
+    }
+    (a0, period)
+}
+
+/// An iterator over the convergents `(p, q)` of a continued fraction, which stops early if overflow
+/// occured.
+///
+/// # Examples
+/// ```
+/// # use number_utils::Convergents;
+/// # fn main() {
+/// assert_eq!(
+///     Convergents::new([4_u32, 2, 6, 7]).collect::<Vec<_>>(),
+///     vec![(4, 1), (9, 2), (58, 13), (415, 93)]
+/// );
+/// assert_eq!(Convergents::new([u8::MAX, 2]).count(), 1);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Convergents<I, T> {
+    terms: Option<I>,
+    numerators: (T, T),
+    denominators: (T, T),
+}
+
+impl<T: UnsignedInteger, I: Iterator<Item = T>> Convergents<I, T> {
+    /// Creates a new iterator over the convergents of the continued fraction with the given terms.
+    pub fn new<J: IntoIterator<IntoIter = I>>(terms: J) -> Self {
+        Self {
+            terms: Some(terms.into_iter()),
+            numerators: (T::one(), T::zero()),
+            denominators: (T::zero(), T::one()),
+        }
+    }
+}
+
+impl<T: UnsignedInteger, I: Iterator<Item = T>> Iterator for Convergents<I, T> {
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.terms.as_mut()?.next()?;
+        // p(k) = a(k) p(k - 1) + p(k - 2), and likewise for q.
+        let next = |(x, y): &(T, T)| a.checked_mul(x)?.checked_add(y);
+        let (Some(p), Some(q)) = (next(&self.numerators), next(&self.denominators)) else {
+            self.terms = None;
+            return None;
+        };
+        self.numerators = (
+            p.clone(),
+            std::mem::replace(&mut self.numerators.0, T::zero()),
+        );
+        self.denominators = (
+            q.clone(),
+            std::mem::replace(&mut self.denominators.0, T::zero()),
+        );
+        Some((p, q))
+    }
+}
+
+/// Compares the values of two continued fractions, treating the terms after the last as infinite.
+fn compare_continued_fractions<T: Ord>(
+    mut a: impl Iterator<Item = T>,
+    mut b: impl Iterator<Item = T>,
+) -> Ordering {
+    let mut i = 0;
+    loop {
+        let ordering = match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(x), Some(y)) => x.cmp(&y),
+        };
+        // Increasing an odd-indexed term decreases the value.
+        if ordering != Ordering::Equal {
+            return if i % 2 == 0 {
+                ordering
+            } else {
+                ordering.reverse()
+            };
+        }
+        i += 1;
+    }
+}
+
+/// Calculates the best rational approximation `(p, q)` with `q <= max_denominator` of the
+/// continued fraction with the given terms, returning None if `max_denominator` is zero or overflow
+/// occured.
+///
+/// The result is the last convergent with a small enough denominator or the semiconvergent after
+/// it, choosing the one closer to the value.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{best_approximation, continued_fraction, sqrt_continued_fraction};
+/// # fn main() {
+/// // π ≈ 3.14159265358979
+/// let pi = continued_fraction(314_159_265_358_979_u64, 100_000_000_000_000);
+/// assert_eq!(best_approximation(pi.clone(), 10), Some((22, 7)));
+/// assert_eq!(best_approximation(pi, 1000), Some((355, 113)));
+///
+/// let (a0, period) = sqrt_continued_fraction(2_u32);
+/// let sqrt_2 = std::iter::once(a0).chain(period.into_iter().cycle());
+/// assert_eq!(best_approximation(sqrt_2, 100), Some((140, 99)));
+/// # }
+/// ```
+pub fn best_approximation<T: UnsignedInteger, I: IntoIterator<Item = T>>(
+    terms: I,
+    max_denominator: T,
+) -> Option<(T, T)> {
+    if max_denominator.is_zero() {
+        return None;
+    }
+    let mut terms = terms.into_iter();
+    let mut seen = vec![];
+    let (mut p1, mut p2) = (T::one(), T::zero());
+    let (mut q1, mut q2) = (T::zero(), T::one());
+    while let Some(a) = terms.next() {
+        let q = a.checked_mul(&q1).and_then(|q| q.checked_add(&q2));
+        if q.as_ref().is_none_or(|q| q > &max_denominator) {
+            // The semiconvergent (p2 + j p1) / (q2 + j q1) is closer than p1 / q1 if and only if
+            // 2j > a, or 2j = a and [a(k + 2); ...] > [a(k); a(k - 1), ..., a(1)].
+            let j = (max_denominator - q2.clone()) / q1.clone();
+            let semiconvergent = match j.cmp(&(a.clone() - j.clone())) {
+                Ordering::Greater => true,
+                Ordering::Less => false,
+                Ordering::Equal => {
+                    let mut reversed: Vec<T> = seen.iter().skip(1).rev().cloned().collect();
+                    // Merge a trailing one into the previous term to get the canonical form.
+                    if reversed.len() > 1 && reversed[reversed.len() - 1].is_one() {
+                        reversed.pop();
+                        let last = reversed.pop()?;
+                        reversed.push(last + T::one());
+                    }
+                    compare_continued_fractions(terms, reversed.into_iter()) == Ordering::Greater
+                }
+            };
+            if !semiconvergent {
+                return Some((p1, q1));
+            }
+            let p = j.checked_mul(&p1)?.checked_add(&p2)?;
+            return Some((p, j * q1 + q2));
+        }
+        let p = a.checked_mul(&p1)?.checked_add(&p2)?;
+        (p1, p2) = (p, p1);
+        (q1, q2) = (q?, q1);
+        seen.push(a);
+    }
+    Some((p1, q1))
+}
@@ -0,0 +1,195 @@
+use crate::{NumberUtils, SignedInteger, UnsignedInteger};
+use num_integer::{ExtendedGcd, Integer};
+
+/// The integer solutions `(x0 + k dx, y0 - k dy)` for every integer `k` of a linear Diophantine
+/// equation `ax + by = c`, where `dx = |b| / gcd(a, b)` and `0 <= x0 < dx` unless `b` is zero.
+///
+/// # Examples
+/// ```
+/// # use number_utils::solve_linear_diophantine;
+/// # fn main() {
+/// let solutions = solve_linear_diophantine(6_i64, 10, 28).unwrap();
+/// assert_eq!(solutions.solution(&0), Some((3, 1)));
+/// assert_eq!(solutions.solution(&-1), Some((-2, 4)));
+/// assert_eq!(
+///     solutions.non_negative_solutions(10, 10).collect::<Vec<_>>(),
+///     vec![(3, 1)]
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LinearDiophantine<T> {
+    pub x0: T,
+    pub y0: T,
+    pub dx: T,
+    pub dy: T,
+}
+
+/// Calculates the range of k with `0 <= u + kv <= max` as lower and upper bounds, which are
+/// missing if `v` is zero, returning None if the range is empty.
+fn k_range<T: SignedInteger>(u: &T, v: &T, max: &T) -> Option<(Option<T>, Option<T>)> {
+    if v.is_zero() {
+        return (!u.is_negative() && u <= max).then_some((None, None));
+    }
+    let (from_zero, from_max) = (-u.clone(), max.clone() - u.clone());
+    if v.is_positive() {
+        Some((Some(from_zero.div_ceil(v)), Some(from_max.div_floor(v))))
+    } else {
+        Some((Some(from_max.div_ceil(v)), Some(from_zero.div_floor(v))))
+    }
+}
+
+impl<T: SignedInteger> LinearDiophantine<T> {
+    /// Calculates the k-th solution `(x0 + k dx, y0 - k dy)`, returning None if overflow occured.
+    pub fn solution(&self, k: &T) -> Option<(T, T)> {
+        let x = self.x0.checked_add(&k.checked_mul(&self.dx)?)?;
+        let y = self.y0.checked_sub(&k.checked_mul(&self.dy)?)?;
+        Some((x, y))
+    }
+
+    /// Creates an iterator over the solutions with `0 <= x <= max_x` and `0 <= y <= max_y` in
+    /// increasing order of `x`, or of `y` if `b` is zero.
+    pub fn non_negative_solutions(&self, max_x: T, max_y: T) -> NonNegativeSolutions<T> {
+        let bounds = k_range(&self.x0, &self.dx, &max_x)
+            .zip(k_range(&self.y0, &-self.dy.clone(), &max_y))
+            .map(|((lo1, hi1), (lo2, hi2))| {
+                let lo = lo1.into_iter().chain(lo2).max();
+                let hi = hi1.into_iter().chain(hi2).min();
+                // dx and dy are not both zero, so both bounds exist.
+                (lo.expect("range is bounded"), hi.expect("range is bounded"))
+            });
+        let (k, end) = bounds.unwrap_or((T::one(), T::zero()));
+        NonNegativeSolutions {
+            equation: self.clone(),
+            k,
+            end,
+        }
+    }
+}
+
+/// An iterator over the non-negative solutions of a linear Diophantine equation in a bounding box,
+/// created by `LinearDiophantine::non_negative_solutions`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NonNegativeSolutions<T> {
+    equation: LinearDiophantine<T>,
+    k: T,
+    end: T,
+}
+
+impl<T: SignedInteger> Iterator for NonNegativeSolutions<T> {
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.k > self.end {
+            return None;
+        }
+        let solution = self.equation.solution(&self.k);
+        self.k = self.k.clone() + T::one();
+        solution
+    }
+}
+
+/// Calculates every integer solution of `ax + by = c`, returning None if there is none, both `a`
+/// and `b` are zero, or overflow occured.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{solve_linear_diophantine, LinearDiophantine};
+/// # fn main() {
+/// assert_eq!(
+///     solve_linear_diophantine(6_i64, -10, 28),
+///     Some(LinearDiophantine { x0: 3, y0: -1, dx: 5, dy: -3 })
+/// );
+/// assert_eq!(solve_linear_diophantine(6_i64, 10, 27), None);
+/// # }
+/// ```
+pub fn solve_linear_diophantine<T: SignedInteger>(
+    a: T,
+    b: T,
+    c: T,
+) -> Option<LinearDiophantine<T>> {
+    if a.is_zero() && b.is_zero() {
+        return None;
+    }
+    let ExtendedGcd { gcd, x, .. } = a.extended_gcd(&b);
+    if !c.is_multiple_of(&gcd) {
+        return None;
+    }
+    let (mut dx, mut dy) = (b.clone() / gcd.clone(), a.clone() / gcd.clone());
+    if dx.is_negative() {
+        (dx, dy) = (-dx, -dy);
+    }
+    let q = c.clone() / gcd;
+    if b.is_zero() {
+        // x = c / a and y = k is arbitrary.
+        return Some(LinearDiophantine {
+            x0: x.checked_mul(&q)?,
+            y0: T::zero(),
+            dx,
+            dy: -T::one(),
+        });
+    }
+    let x0 = x
+        .mod_floor(&dx)
+        .checked_mul(&q.mod_floor(&dx))?
+        .mod_floor(&dx);
+    let y0 = c.checked_sub(&a.checked_mul(&x0)?)? / b;
+    Some(LinearDiophantine { x0, y0, dx, dy })
+}
+
+/// Calculates the Frobenius number, the largest amount that cannot be paid with the given coin
+/// denominations, returning None if the denominations are not coprime, include one, or overflow
+/// occured.
+///
+/// Two denominations use `ab - a - b`, and more use the round robin algorithm in
+/// `O(k a)` time and `O(a)` space for the smallest denomination `a`.
+///
+/// # Examples
+/// ```
+/// # use number_utils::frobenius_number;
+/// # fn main() {
+/// assert_eq!(frobenius_number(&[3_u32, 5]), Some(7));
+/// assert_eq!(frobenius_number(&[6_u32, 9, 20]), Some(43));
+/// assert_eq!(frobenius_number(&[4_u32, 6]), None);
+/// # }
+/// ```
+pub fn frobenius_number<T: UnsignedInteger>(coins: &[T]) -> Option<T> {
+    let mut coins: Vec<T> = coins.iter().filter(|c| !c.is_zero()).cloned().collect();
+    coins.sort();
+    coins.dedup();
+    if !T::gcd_all(coins.iter().cloned()).is_one() || coins[0].is_one() {
+        return None;
+    }
+    if let [a, b] = &coins[..] {
+        return a.checked_mul(b)?.checked_sub(a)?.checked_sub(b);
+    }
+    // shortest[r] is the smallest payable amount congruent to r modulo the smallest coin.
+    let a = coins[0].to_usize()?;
+    let mut shortest = vec![None; a];
+    shortest[0] = Some(T::zero());
+    for coin in &coins[1..] {
+        let step = (coin.clone() % coins[0].clone()).to_usize()?;
+        let d = Integer::gcd(&a, &step);
+        for p in 0..d {
+            let Some((mut r, mut n)) = (p..a)
+                .step_by(d)
+                .filter_map(|r| Some((r, shortest[r].clone()?)))
+                .min_by(|x, y| x.1.cmp(&y.1))
+            else {
+                continue;
+            };
+            for _ in 1..a / d {
+                r = (r + step) % a;
+                n = n.checked_add(coin)?;
+                if let Some(m) = &shortest[r] {
+                    if m < &n {
+                        n = m.clone();
+                    }
+                }
+                shortest[r] = Some(n.clone());
+            }
+        }
+    }
+    let largest = shortest.into_iter().max()??;
+    largest.checked_sub(&coins[0])
+}
@@ -0,0 +1,39 @@
+use std::fmt;
+
+/// The error type for calculations that can fail for more than one reason.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{Error, NumberUtils};
+/// # fn main() {
+/// assert_eq!(u32::MAX.try_factorial(), Err(Error::Overflow));
+/// assert_eq!(0_u32.try_pisano_period(), Err(Error::InvalidModulus));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Error {
+    /// The result does not fit in the type.
+    Overflow,
+    /// An input that must be non-negative is negative.
+    NegativeInput,
+    /// The modulus is zero or otherwise unsupported.
+    InvalidModulus,
+    /// The base is out of the supported range.
+    InvalidBase,
+    /// An input is out of the domain of the calculation or too large to index a table.
+    InvalidInput,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "arithmetic overflow"),
+            Self::NegativeInput => write!(f, "negative input"),
+            Self::InvalidModulus => write!(f, "invalid modulus"),
+            Self::InvalidBase => write!(f, "invalid base"),
+            Self::InvalidInput => write!(f, "invalid input"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
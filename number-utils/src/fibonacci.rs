@@ -0,0 +1,188 @@
+use crate::{NumberUtils, UnsignedInteger};
+
+/// Calculates the binary digits of `n`, most significant digit first.
+pub(crate) fn bits<T: UnsignedInteger>(n: &T) -> Vec<bool> {
+    let two = T::one() + T::one();
+    let mut n = n.clone();
+    let mut bits = vec![];
+    while !n.is_zero() {
+        let (q, r) = n.div_rem(&two);
+        bits.push(!r.is_zero());
+        n = q;
+    }
+    bits.reverse();
+    bits
+}
+
+/// Calculates `a * b % m` without overflowing.
+pub(crate) fn mul_mod<T: UnsignedInteger>(a: &T, b: &T, m: &T) -> T {
+    let (mut a, b) = (a.clone() % m.clone(), b.clone() % m.clone());
+    if let Some(ab) = a.checked_mul(&b) {
+        return ab % m.clone();
+    }
+    let mut result = T::zero();
+    for bit in bits(&b).into_iter().rev() {
+        if bit {
+            result = add_mod(&result, &a, m);
+        }
+        a = add_mod(&a, &a, m);
+    }
+    result
+}
+
+/// Calculates `(a + b) % m` for `a, b < m` without overflowing.
+pub(crate) fn add_mod<T: UnsignedInteger>(a: &T, b: &T, m: &T) -> T {
+    let c = m.clone() - b.clone();
+    if a >= &c {
+        a.clone() - c
+    } else {
+        a.clone() + b.clone()
+    }
+}
+
+/// Calculates `(a - b) % m` for `a, b < m` without overflowing.
+pub(crate) fn sub_mod<T: UnsignedInteger>(a: &T, b: &T, m: &T) -> T {
+    if a >= b {
+        a.clone() - b.clone()
+    } else {
+        m.clone() - (b.clone() - a.clone())
+    }
+}
+
+/// Calculates `(F(n), F(n + 1))` using fast doubling, returning None if overflow occured.
+pub(crate) fn checked_fibonacci_pair<T: UnsignedInteger>(n: &T) -> Option<(T, T)> {
+    let (mut a, mut b) = (T::zero(), T::one());
+    for bit in bits(n) {
+        let c = a.checked_mul(&(b.checked_add(&b)? - a.clone()))?;
+        let d = a.checked_mul(&a)?.checked_add(&b.checked_mul(&b)?)?;
+        (a, b) = if bit {
+            let e = c.checked_add(&d)?;
+            (d, e)
+        } else {
+            (c, d)
+        };
+    }
+    Some((a, b))
+}
+
+/// Calculates n-th Fibonacci number using fast doubling, returning None if overflow occured.
+///
+/// Only `F(n / 2)` and `F(n / 2 + 1)` are calculated before the last step, so every computed
+/// value is at most `F(n)`.
+pub(crate) fn checked_fibonacci<T: UnsignedInteger>(n: &T) -> Option<T> {
+    let two = T::one() + T::one();
+    let (a, b) = checked_fibonacci_pair(&(n.clone() / two))?;
+    if n.is_odd() {
+        a.checked_mul(&a)?.checked_add(&b.checked_mul(&b)?)
+    } else {
+        a.checked_mul(&(b.checked_add(&b)? - a.clone()))
+    }
+}
+
+/// Calculates n-th Lucas number `L(n) = 2F(n - 1) + F(n)`, returning None if overflow occured.
+pub(crate) fn checked_lucas<T: UnsignedInteger>(n: &T) -> Option<T> {
+    if n.is_zero() {
+        return Some(T::one() + T::one());
+    }
+    let (a, b) = checked_fibonacci_pair(&(n.clone() - T::one()))?;
+    a.checked_add(&a)?.checked_add(&b)
+}
+
+/// Calculates `(F(n) % m, F(n + 1) % m)` using fast doubling.
+pub(crate) fn fibonacci_pair_mod<T: UnsignedInteger>(n: &T, m: &T) -> (T, T) {
+    if m.is_one() {
+        return (T::zero(), T::zero());
+    }
+    let (mut a, mut b) = (T::zero(), T::one());
+    for bit in bits(n) {
+        let c = mul_mod(&a, &sub_mod(&add_mod(&b, &b, m), &a, m), m);
+        let d = add_mod(&mul_mod(&a, &a, m), &mul_mod(&b, &b, m), m);
+        if bit {
+            let e = add_mod(&c, &d, m);
+            (a, b) = (d, e);
+        } else {
+            (a, b) = (c, d);
+        }
+    }
+    (a, b)
+}
+
+/// Calculates prime factorization by trial division.
+pub(crate) fn prime_factorization<T: UnsignedInteger>(mut n: T) -> Vec<T> {
+    let two = T::one() + T::one();
+    let mut prime_factors = vec![];
+    while n > T::one() && n.is_even() {
+        prime_factors.push(two.clone());
+        n = n / two.clone();
+    }
+    let mut i = two.clone() + T::one();
+    while i <= n.clone() / i.clone() {
+        while n.is_multiple_of(&i) {
+            prime_factors.push(i.clone());
+            n = n / i.clone();
+        }
+        i = i + two.clone();
+    }
+    if n > T::one() {
+        prime_factors.push(n);
+    }
+    prime_factors
+}
+
+/// Calculates the Pisano period of a prime number, returning None if overflow occured.
+fn checked_pisano_period_of_prime<T: UnsignedInteger>(p: &T) -> Option<T> {
+    match p.to_u8() {
+        Some(2) => return T::from_u8(3),
+        Some(5) => return T::from_u8(20),
+        _ => {}
+    }
+    let r = (p.clone() % T::from_u8(10)?).to_u8()?;
+    // The period divides p - 1 if p = ±1 (mod 10). Otherwise it divides 2(p + 1) but not p + 1,
+    // so only odd factors of p + 1 are removed, which keeps 2(p + 1) / q in range.
+    let (mut period, scale) = if r == 1 || r == 9 {
+        (p.clone() - T::one(), T::one())
+    } else {
+        (p.checked_add(&T::one())?, T::one() + T::one())
+    };
+    let mut prime_factors = prime_factorization(period.clone());
+    prime_factors.dedup();
+    for q in prime_factors {
+        if !scale.is_one() && q.is_even() {
+            continue;
+        }
+        while period.is_multiple_of(&q) {
+            let candidate = (period.clone() / q.clone()).checked_mul(&scale)?;
+            if fibonacci_pair_mod(&candidate, p) != (T::zero(), T::one()) {
+                break;
+            }
+            period = period / q.clone();
+        }
+    }
+    period.checked_mul(&scale)
+}
+
+/// Calculates the Pisano period, returning None if `m` is zero or overflow occured.
+pub(crate) fn checked_pisano_period<T: UnsignedInteger>(m: &T) -> Option<T> {
+    if m.is_zero() {
+        return None;
+    }
+    let prime_factors = prime_factorization(m.clone());
+    let mut period = T::one();
+    let mut i = 0;
+    while i < prime_factors.len() {
+        let p = &prime_factors[i];
+        let mut j = i + 1;
+        while j < prime_factors.len() && &prime_factors[j] == p {
+            j += 1;
+        }
+        // π(p^k) = p^(k - 1) π(p).
+        let mut prime_power_period = checked_pisano_period_of_prime(p)?;
+        for _ in i + 1..j {
+            prime_power_period = prime_power_period.checked_mul(p)?;
+        }
+        period = (period.clone() / NumberUtils::gcd(&period, prime_power_period.clone()))
+            .checked_mul(&prime_power_period)?;
+        i = j;
+    }
+    Some(period)
+}
@@ -0,0 +1,394 @@
+use crate::{combinatorics, fibonacci, partitions, Error, NumberUtils, UnsignedInteger};
+use num_integer::Integer;
+
+/// Calculates the product of `lo, lo + step, ...` up to `hi`, returning None if overflow occured.
+fn checked_product<T: UnsignedInteger>(mut lo: T, hi: &T, step: &T) -> Option<T> {
+    let mut product = T::one();
+    while &lo <= hi {
+        product = product.checked_mul(&lo)?;
+        lo = lo + step.clone();
+    }
+    Some(product)
+}
+
+/// Checks that `base` is in the range 2 to 256.
+fn is_digit_base<T: UnsignedInteger>(base: &T) -> bool {
+    matches!(base.to_u16(), Some(2..=256))
+}
+
+impl<T: UnsignedInteger> NumberUtils for T {
+    fn factorial(&self) -> Self {
+        self.checked_factorial()
+            .expect("attempt to calculate factorial with overflow")
+    }
+
+    fn permutation(&self, k: u32) -> Self {
+        self.checked_permutation(k)
+            .expect("attempt to calculate permutation with overflow")
+    }
+
+    fn combination(&self, k: u32) -> Self {
+        self.checked_combination(k)
+            .expect("attempt to calculate combination with overflow")
+    }
+
+    fn double_factorial(&self) -> Self {
+        self.checked_double_factorial()
+            .expect("attempt to calculate double factorial with overflow")
+    }
+
+    fn gcd(&self, m: Self) -> Self {
+        Integer::gcd(self, &m)
+    }
+
+    fn lcm(&self, m: Self) -> Self {
+        self.checked_lcm(m)
+            .expect("attempt to calculate lcm with overflow")
+    }
+
+    fn gcd_all<I: IntoIterator<Item = Self>>(ns: I) -> Self {
+        let mut gcd = Self::zero();
+        for n in ns {
+            gcd = NumberUtils::gcd(&gcd, n);
+            if gcd.is_one() {
+                break;
+            }
+        }
+        gcd
+    }
+
+    fn lcm_all<I: IntoIterator<Item = Self>>(ns: I) -> Self {
+        Self::checked_lcm_all(ns).expect("attempt to calculate lcm with overflow")
+    }
+
+    fn fibonacci(&self) -> Self {
+        self.checked_fibonacci()
+            .expect("attempt to calculate Fibonacci number with overflow")
+    }
+
+    fn lucas(&self) -> Self {
+        self.checked_lucas()
+            .expect("attempt to calculate Lucas number with overflow")
+    }
+
+    fn checked_factorial(&self) -> Option<Self> {
+        checked_product(Self::one() + Self::one(), self, &Self::one())
+    }
+
+    fn checked_permutation(&self, k: u32) -> Option<Self> {
+        let k = match Self::from_u32(k) {
+            Some(k) if &k <= self => k,
+            _ => return Some(Self::zero()),
+        };
+        checked_product(self.clone() - k + Self::one(), self, &Self::one())
+    }
+
+    fn checked_combination(&self, k: u32) -> Option<Self> {
+        match Self::from_u32(k) {
+            Some(k) => combinatorics::checked_binomial(self, &k),
+            None => Some(Self::zero()),
+        }
+    }
+
+    fn checked_fibonacci(&self) -> Option<Self> {
+        fibonacci::checked_fibonacci(self)
+    }
+
+    fn checked_lucas(&self) -> Option<Self> {
+        fibonacci::checked_lucas(self)
+    }
+
+    fn checked_double_factorial(&self) -> Option<Self> {
+        let two = Self::one() + Self::one();
+        let lo = if self.is_odd() {
+            Self::one()
+        } else {
+            two.clone()
+        };
+        checked_product(lo, self, &two)
+    }
+
+    fn checked_lcm(&self, m: Self) -> Option<Self> {
+        if self.is_zero() || m.is_zero() {
+            return Some(Self::zero());
+        }
+        (self.clone() / NumberUtils::gcd(self, m.clone())).checked_mul(&m)
+    }
+
+    fn checked_lcm_all<I: IntoIterator<Item = Self>>(ns: I) -> Option<Self> {
+        let mut lcm = Some(Self::one());
+        for n in ns {
+            if n.is_zero() {
+                return Some(n);
+            }
+            // Keep reading after overflow, since a later zero still makes the result zero.
+            lcm = lcm.and_then(|lcm| lcm.checked_lcm(n));
+        }
+        lcm
+    }
+
+    fn checked_multinomial(ks: &[Self]) -> Option<Self> {
+        combinatorics::checked_multinomial(ks)
+    }
+
+    fn checked_derangement(&self) -> Option<Self> {
+        combinatorics::checked_derangement(self)
+    }
+
+    fn checked_rencontres(&self, k: u32) -> Option<Self> {
+        match Self::from_u32(k) {
+            Some(k) => combinatorics::checked_rencontres(self, &k),
+            None => Some(Self::zero()),
+        }
+    }
+
+    fn checked_catalan(&self) -> Option<Self> {
+        combinatorics::checked_catalan(self)
+    }
+
+    fn checked_stirling_first(&self, k: u32) -> Option<Self> {
+        combinatorics::checked_stirling_first(self, k as usize)
+    }
+
+    fn checked_stirling_second(&self, k: u32) -> Option<Self> {
+        combinatorics::checked_stirling_second(self, k as usize)
+    }
+
+    fn checked_bell(&self) -> Option<Self> {
+        combinatorics::checked_bell(self)
+    }
+
+    fn checked_lah(&self, k: u32) -> Option<Self> {
+        combinatorics::checked_lah(self, k as usize)
+    }
+
+    fn checked_eulerian(&self, k: u32) -> Option<Self> {
+        combinatorics::checked_eulerian(self, k as usize)
+    }
+
+    fn checked_partitions(&self) -> Option<Self> {
+        partitions::checked_partitions(self)
+    }
+
+    fn checked_distinct_partitions(&self) -> Option<Self> {
+        partitions::checked_distinct_partitions(self)
+    }
+
+    fn checked_partitions_at_most(&self, k: u32) -> Option<Self> {
+        partitions::checked_partitions_at_most(self, k as usize)
+    }
+
+    fn fibonacci_mod(&self, m: Self) -> Self {
+        fibonacci::fibonacci_pair_mod(self, &m).0
+    }
+
+    fn pisano_period(&self) -> Self {
+        self.checked_pisano_period()
+            .expect("attempt to calculate Pisano period with overflow or modulo zero")
+    }
+
+    fn checked_pisano_period(&self) -> Option<Self> {
+        fibonacci::checked_pisano_period(self)
+    }
+
+    fn digits(&self) -> Self {
+        self.digits_in(Self::from_u8(10).expect("base must fit in the type"))
+    }
+
+    fn digits_in(&self, base: Self) -> Self {
+        assert!(base > Self::one(), "base must be at least 2");
+        let mut n = self.clone() / base.clone();
+        let mut digits = Self::one();
+        while !n.is_zero() {
+            digits = digits + Self::one();
+            n = n / base.clone();
+        }
+        digits
+    }
+
+    fn to_digits(&self, base: Self) -> Vec<u8> {
+        assert!(is_digit_base(&base), "base must be in the range 2 to 256");
+        let mut n = self.clone();
+        let mut digits = vec![];
+        loop {
+            let (q, r) = n.div_rem(&base);
+            digits.push(r.to_u8().expect("digit must be less than 256"));
+            n = q;
+            if n.is_zero() {
+                break;
+            }
+        }
+        digits.reverse();
+        digits
+    }
+
+    fn from_digits(digits: &[u8], base: Self) -> Option<Self> {
+        digits.iter().try_fold(Self::zero(), |n, &d| {
+            let d = Self::from_u8(d).filter(|d| d < &base)?;
+            n.checked_mul(&base)?.checked_add(&d)
+        })
+    }
+
+    fn digit_sum(&self, base: Self) -> Self {
+        assert!(base > Self::one(), "base must be at least 2");
+        let mut n = self.clone();
+        let mut sum = Self::zero();
+        while !n.is_zero() {
+            let (q, r) = n.div_rem(&base);
+            sum = sum + r;
+            n = q;
+        }
+        sum
+    }
+
+    fn digital_root(&self, base: Self) -> Self {
+        assert!(base > Self::one(), "base must be at least 2");
+        if self.is_zero() {
+            Self::zero()
+        } else {
+            Self::one() + (self.clone() - Self::one()) % (base - Self::one())
+        }
+    }
+
+    fn reverse_digits(&self, base: Self) -> Self {
+        self.checked_reverse_digits(base)
+            .expect("attempt to reverse digits with overflow")
+    }
+
+    fn checked_reverse_digits(&self, base: Self) -> Option<Self> {
+        assert!(base > Self::one(), "base must be at least 2");
+        let mut n = self.clone();
+        let mut reversed = Self::zero();
+        while !n.is_zero() {
+            let (q, r) = n.div_rem(&base);
+            reversed = reversed.checked_mul(&base)?.checked_add(&r)?;
+            n = q;
+        }
+        Some(reversed)
+    }
+
+    fn is_palindrome(&self, base: Self) -> bool {
+        self.checked_reverse_digits(base).as_ref() == Some(self)
+    }
+
+    fn try_factorial(&self) -> Result<Self, Error> {
+        self.checked_factorial().ok_or(Error::Overflow)
+    }
+
+    fn try_permutation(&self, k: u32) -> Result<Self, Error> {
+        self.checked_permutation(k).ok_or(Error::Overflow)
+    }
+
+    fn try_combination(&self, k: u32) -> Result<Self, Error> {
+        self.checked_combination(k).ok_or(Error::Overflow)
+    }
+
+    fn try_fibonacci(&self) -> Result<Self, Error> {
+        self.checked_fibonacci().ok_or(Error::Overflow)
+    }
+
+    fn try_lucas(&self) -> Result<Self, Error> {
+        self.checked_lucas().ok_or(Error::Overflow)
+    }
+
+    fn try_double_factorial(&self) -> Result<Self, Error> {
+        self.checked_double_factorial().ok_or(Error::Overflow)
+    }
+
+    fn try_lcm(&self, m: Self) -> Result<Self, Error> {
+        self.checked_lcm(m).ok_or(Error::Overflow)
+    }
+
+    fn try_lcm_all<I: IntoIterator<Item = Self>>(ns: I) -> Result<Self, Error> {
+        Self::checked_lcm_all(ns).ok_or(Error::Overflow)
+    }
+
+    fn try_multinomial(ks: &[Self]) -> Result<Self, Error> {
+        Self::checked_multinomial(ks).ok_or(Error::Overflow)
+    }
+
+    fn try_derangement(&self) -> Result<Self, Error> {
+        self.checked_derangement().ok_or(Error::Overflow)
+    }
+
+    fn try_rencontres(&self, k: u32) -> Result<Self, Error> {
+        self.checked_rencontres(k).ok_or(Error::Overflow)
+    }
+
+    fn try_catalan(&self) -> Result<Self, Error> {
+        self.checked_catalan().ok_or(Error::Overflow)
+    }
+
+    fn try_stirling_first(&self, k: u32) -> Result<Self, Error> {
+        self.checked_stirling_first(k).ok_or(Error::Overflow)
+    }
+
+    fn try_stirling_second(&self, k: u32) -> Result<Self, Error> {
+        self.checked_stirling_second(k).ok_or(Error::Overflow)
+    }
+
+    fn try_bell(&self) -> Result<Self, Error> {
+        self.checked_bell().ok_or(Error::Overflow)
+    }
+
+    fn try_lah(&self, k: u32) -> Result<Self, Error> {
+        self.checked_lah(k).ok_or(Error::Overflow)
+    }
+
+    fn try_eulerian(&self, k: u32) -> Result<Self, Error> {
+        self.checked_eulerian(k).ok_or(Error::Overflow)
+    }
+
+    fn try_partitions(&self) -> Result<Self, Error> {
+        self.checked_partitions().ok_or(Error::Overflow)
+    }
+
+    fn try_distinct_partitions(&self) -> Result<Self, Error> {
+        self.checked_distinct_partitions().ok_or(Error::Overflow)
+    }
+
+    fn try_partitions_at_most(&self, k: u32) -> Result<Self, Error> {
+        self.checked_partitions_at_most(k).ok_or(Error::Overflow)
+    }
+
+    fn try_fibonacci_mod(&self, m: Self) -> Result<Self, Error> {
+        if m.is_zero() {
+            return Err(Error::InvalidModulus);
+        }
+        Ok(self.fibonacci_mod(m))
+    }
+
+    fn try_pisano_period(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::InvalidModulus);
+        }
+        self.checked_pisano_period().ok_or(Error::Overflow)
+    }
+
+    fn try_to_digits(&self, base: Self) -> Result<Vec<u8>, Error> {
+        if !is_digit_base(&base) {
+            return Err(Error::InvalidBase);
+        }
+        Ok(self.to_digits(base))
+    }
+
+    fn try_from_digits(digits: &[u8], base: Self) -> Result<Self, Error> {
+        if base <= Self::one() {
+            return Err(Error::InvalidBase);
+        }
+        if digits
+            .iter()
+            .any(|&d| Self::from_u8(d).is_none_or(|d| d >= base))
+        {
+            return Err(Error::InvalidInput);
+        }
+        Self::from_digits(digits, base).ok_or(Error::Overflow)
+    }
+
+    fn try_reverse_digits(&self, base: Self) -> Result<Self, Error> {
+        if base <= Self::one() {
+            return Err(Error::InvalidBase);
+        }
+        self.checked_reverse_digits(base).ok_or(Error::Overflow)
+    }
+}
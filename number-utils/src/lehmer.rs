@@ -0,0 +1,215 @@
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::{ToPrimitive, Zero};
+
+/// Operands with at least this many bits are reduced with half-gcd before Lehmer's algorithm.
+const HALF_GCD_THRESHOLD: u64 = 1 << 16;
+
+/// Half-gcd inputs with fewer bits than this are reduced with Lehmer's algorithm directly.
+const HALF_GCD_BASE: u64 = 1 << 13;
+
+/// Product `M` of Euclid steps `[[q, 1], [1, 0]]`, so that `(a, b) = M (a', b')` where `(a', b')`
+/// is the reduced pair.
+struct Reduction {
+    m: [BigUint; 4],
+    quotients: Vec<BigUint>,
+}
+
+impl Reduction {
+    fn identity() -> Self {
+        Reduction {
+            m: [1_u8.into(), 0_u8.into(), 0_u8.into(), 1_u8.into()],
+            quotients: vec![],
+        }
+    }
+
+    /// Appends a Euclid step with quotient `q`.
+    fn push(&mut self, q: BigUint) {
+        let [m00, m01, m10, m11] = std::mem::take(&mut self.m);
+        self.m = [&m00 * &q + m01, m00, &m10 * &q + m11, m10];
+        self.quotients.push(q);
+    }
+
+    /// Appends the Euclid steps of a Lehmer step with cofactors `[A, B, C, D]`, whose quotients
+    /// have already been pushed.
+    fn push_cofactors(&mut self, [p, q, r, s]: [i128; 4]) {
+        // The steps multiply to the inverse of [[A, B], [C, D]], which has determinant ±1.
+        let [p, q, r, s] = [p, q, r, s].map(|c| c.unsigned_abs() as u64);
+        let [m00, m01, m10, m11] = &self.m;
+        self.m = [
+            m00 * s + m01 * r,
+            m00 * q + m01 * p,
+            m10 * s + m11 * r,
+            m10 * q + m11 * p,
+        ];
+    }
+
+    /// Removes the last Euclid step, returning its quotient.
+    fn pop(&mut self) -> Option<BigUint> {
+        let q = self.quotients.pop()?;
+        let [m00, m01, m10, m11] = std::mem::take(&mut self.m);
+        self.m = [m01.clone(), m00 - &q * m01, m11.clone(), m10 - &q * m11];
+        Some(q)
+    }
+
+    /// Appends all Euclid steps of `other`.
+    fn extend(&mut self, other: Reduction) {
+        let [a00, a01, a10, a11] = &self.m;
+        let [b00, b01, b10, b11] = &other.m;
+        self.m = [
+            a00 * b00 + a01 * b10,
+            a00 * b01 + a01 * b11,
+            a10 * b00 + a11 * b10,
+            a10 * b01 + a11 * b11,
+        ];
+        self.quotients.extend(other.quotients);
+    }
+
+    /// Calculates the reduced pair `M^-1 (a, b)`, dropping trailing steps until it is a valid
+    /// remainder pair of `a` and `b`.
+    fn apply(&mut self, a: &BigUint, b: &BigUint) -> (BigUint, BigUint) {
+        let [m00, m01, m10, m11] = &self.m;
+        // M has determinant (-1)^k after k steps.
+        let (mut x, mut y) = (
+            BigInt::from(m11 * a) - BigInt::from(m01 * b),
+            BigInt::from(m00 * b) - BigInt::from(m10 * a),
+        );
+        if self.quotients.len() % 2 == 1 {
+            (x, y) = (-x, -y);
+        }
+        while !self.quotients.is_empty() && (y.sign() == Sign::Minus || x <= y) {
+            let q = self.pop().expect("quotients are not empty");
+            (x, y) = (BigInt::from(q) * &x + y, x);
+        }
+        (x.into_parts().1, y.into_parts().1)
+    }
+}
+
+/// Calculates the (at most 63) bits of `n` from bit `shift` on.
+fn bits_from(n: &BigUint, shift: u64) -> i128 {
+    let mut digits = n.iter_u64_digits().skip((shift / 64) as usize);
+    let lo = u128::from(digits.next().unwrap_or(0));
+    let hi = u128::from(digits.next().unwrap_or(0));
+    ((hi << 64 | lo) >> (shift % 64)) as i128
+}
+
+/// Simulates Euclid's algorithm on the leading 63 bits of `a >= b`, returning the cofactors
+/// `[A, B, C, D]` with `(Aa + Bb, Ca + Db)` equal to a later remainder pair of `a` and `b`, and
+/// pushing the quotients of the steps taken.
+///
+/// Only steps whose quotients agree for every completion of the leading bits are taken, so every
+/// cofactor is less than `2^63` in absolute value.
+fn lehmer_cofactors(
+    a: &BigUint,
+    b: &BigUint,
+    mut quotients: Option<&mut Vec<BigUint>>,
+) -> [i128; 4] {
+    let shift = a.bits().saturating_sub(63);
+    let (mut x, mut y) = (bits_from(a, shift), bits_from(b, shift));
+    let [mut p, mut q, mut r, mut s] = [1_i128, 0, 0, 1];
+    while y + r > 0 && y + s > 0 {
+        let quotient = (x + p) / (y + r);
+        if quotient < 1 || quotient != (x + q) / (y + s) {
+            break;
+        }
+        (p, r) = (r, p - quotient * r);
+        (q, s) = (s, q - quotient * s);
+        (x, y) = (y, x - quotient * y);
+        if let Some(quotients) = quotients.as_mut() {
+            quotients.push(BigUint::from(quotient as u64));
+        }
+    }
+    [p, q, r, s]
+}
+
+/// Calculates `pa + qb`, which must be non-negative.
+fn combine(a: &BigUint, b: &BigUint, p: i128, q: i128) -> BigUint {
+    let (pa, qb) = (a * p.unsigned_abs() as u64, b * q.unsigned_abs() as u64);
+    match (p < 0, q < 0) {
+        (false, false) => pa + qb,
+        (false, true) => pa - qb,
+        (true, false) => qb - pa,
+        (true, true) => unreachable!("cofactors have opposite signs"),
+    }
+}
+
+/// Calculates `(a', b')` after a single step of Lehmer's algorithm on `a >= b`.
+fn lehmer_step(a: BigUint, b: BigUint) -> (BigUint, BigUint) {
+    let [p, q, r, s] = lehmer_cofactors(&a, &b, None);
+    if q == 0 {
+        // The leading bits did not determine any quotient, so a full division step is taken.
+        let remainder = &a % &b;
+        return (b, remainder);
+    }
+    (combine(&a, &b, p, q), combine(&a, &b, r, s))
+}
+
+/// Calculates a reduction of `a >= b` to a remainder pair whose smaller element has about half as
+/// many bits, using recursion on the leading bits.
+fn half_gcd(a: &BigUint, b: &BigUint) -> (Reduction, BigUint, BigUint) {
+    let n = a.bits();
+    let half = n / 2 + 1;
+    let mut reduction = Reduction::identity();
+    if b.bits() <= half {
+        return (reduction, a.clone(), b.clone());
+    }
+    if n < HALF_GCD_BASE {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        while b.bits() > half {
+            let cofactors = if b.bits() > 64 {
+                lehmer_cofactors(&a, &b, Some(&mut reduction.quotients))
+            } else {
+                [1, 0, 0, 1]
+            };
+            if cofactors[1] == 0 {
+                let (quotient, remainder) = a.div_rem(&b);
+                reduction.push(quotient);
+                (a, b) = (b, remainder);
+            } else {
+                let [p, q, r, s] = cofactors;
+                reduction.push_cofactors(cofactors);
+                (a, b) = (combine(&a, &b, p, q), combine(&a, &b, r, s));
+            }
+        }
+        return (reduction, a, b);
+    }
+    let p = n / 2;
+    let (top, _, _) = half_gcd(&(a >> p), &(b >> p));
+    reduction = top;
+    let (a, b) = reduction.apply(a, b);
+    if b.bits() <= half {
+        return (reduction, a, b);
+    }
+    let (quotient, remainder) = a.div_rem(&b);
+    reduction.push(quotient);
+    let (a, b) = (b, remainder);
+    if b.bits() <= half {
+        return (reduction, a, b);
+    }
+    let p = (2 * half).saturating_sub(a.bits());
+    let (mut top, _, _) = half_gcd(&(&a >> p), &(&b >> p));
+    let (a, b) = top.apply(&a, &b);
+    reduction.extend(top);
+    (reduction, a, b)
+}
+
+/// Calculates greatest common divisor of `a` and `b` using Lehmer's algorithm, preceded by
+/// half-gcd reductions for very large inputs.
+pub(crate) fn gcd(a: BigUint, b: BigUint) -> BigUint {
+    let (mut a, mut b) = if a < b { (b, a) } else { (a, b) };
+    while b.bits() >= HALF_GCD_THRESHOLD {
+        let (_, x, y) = half_gcd(&a, &b);
+        (a, b) = (x, y);
+        if !b.is_zero() {
+            let remainder = &a % &b;
+            (a, b) = (b, remainder);
+        }
+    }
+    while b.bits() > 64 {
+        (a, b) = lehmer_step(a, b);
+    }
+    match b.to_u64() {
+        Some(0) | None => a,
+        Some(b) => Integer::gcd(&(a % b).to_u64().expect("remainder is less than b"), &b).into(),
+    }
+}
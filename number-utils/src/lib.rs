@@ -17,11 +17,44 @@
 //! ```
 #[cfg(feature = "num-bigint")]
 pub mod bigint;
-mod macros;
+mod combinations;
+mod combinatorics;
+mod continued_fraction;
+mod diophantine;
+mod error;
+mod fibonacci;
+mod gamma;
+mod impls;
+#[cfg(feature = "num-bigint")]
+mod lehmer;
 mod number_utils;
+mod partitions;
+mod permutations;
+mod ranking;
+#[cfg(feature = "num-bigint")]
+mod rational;
 mod test;
 
 pub use crate::{
     bigint::*,
-    number_utils::{pascals_triangle, NumberUtils},
+    combinations::{Combinations, CombinationsWithRepetition},
+    continued_fraction::{
+        best_approximation, continued_fraction, sqrt_continued_fraction, Convergents,
+    },
+    diophantine::{
+        frobenius_number, solve_linear_diophantine, LinearDiophantine, NonNegativeSolutions,
+    },
+    error::Error,
+    gamma::{ln_binomial, ln_factorial, ln_gamma, ln_gamma_stirling},
+    number_utils::{
+        bell_triangle, eulerian_triangle, lah_triangle, pascals_triangle, stirling_first_triangle,
+        stirling_second_triangle, NumberUtils, SignedInteger, UnsignedInteger,
+    },
+    partitions::Partitions,
+    permutations::{next_permutation, prev_permutation, Permutations},
+    ranking::{
+        rank_combination, rank_permutation, try_rank_combination, try_rank_permutation,
+        try_unrank_combination, try_unrank_permutation, unrank_combination, unrank_permutation,
+    },
+    rational::Rational,
 };
@@ -1,7 +1,72 @@
-use crate::impl_number_utils_for;
+use crate::Error;
+use num_integer::Integer;
+use num_traits::{
+    CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, Signed, ToPrimitive, Unsigned,
+};
 use std::marker::Sized;
 
-impl_number_utils_for!(u32, u64, u128, usize);
+/// Unsigned integer types `NumberUtils` is implemented for.
+///
+/// This is implemented for every type satisfying its bounds, which include the primitive unsigned
+/// integers, `BigUint` and user-defined integer types built on `num-traits`. `PrimInt` is not
+/// required so that arbitrary precision integers are covered by the same implementation.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{NumberUtils, UnsignedInteger};
+/// # use num_bigint::BigUint;
+/// fn sum_of_factorials<T: UnsignedInteger>(n: T) -> T {
+///     let mut sum = T::zero();
+///     let mut i = T::zero();
+///     while i <= n {
+///         sum = sum + i.factorial();
+///         i = i + T::one();
+///     }
+///     sum
+/// }
+///
+/// # fn main() {
+/// assert_eq!(sum_of_factorials(5_u32), 154);
+/// assert_eq!(sum_of_factorials(BigUint::from(5_u32)), BigUint::from(154_u32));
+/// # }
+/// ```
+pub trait UnsignedInteger:
+    Clone + Integer + Unsigned + CheckedAdd + CheckedSub + CheckedMul + FromPrimitive + ToPrimitive
+{
+}
+
+impl<T> UnsignedInteger for T where
+    T: Clone
+        + Integer
+        + Unsigned
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + FromPrimitive
+        + ToPrimitive
+{
+}
+
+/// Signed integer types the calculations on integers of either sign are implemented for.
+///
+/// Like `UnsignedInteger`, this is implemented for every type satisfying its bounds, which include
+/// the primitive signed integers and `BigInt`.
+pub trait SignedInteger:
+    Clone + Integer + Signed + CheckedAdd + CheckedSub + CheckedMul + FromPrimitive + ToPrimitive
+{
+}
+
+impl<T> SignedInteger for T where
+    T: Clone
+        + Integer
+        + Signed
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + FromPrimitive
+        + ToPrimitive
+{
+}
 
 pub trait NumberUtils {
     /// Calculates factorial.
@@ -43,6 +108,19 @@ pub trait NumberUtils {
     /// ```
     fn combination(&self, k: u32) -> Self;
 
+    /// Calculates double factorial.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 7;
+    /// assert_eq!(n.double_factorial(), 105);
+    /// # }
+    /// ```
+    fn double_factorial(&self) -> Self;
+
     /// Calculates greatest common divisor of two integers.
     ///
     /// # Examples
@@ -58,6 +136,9 @@ pub trait NumberUtils {
 
     /// Calculates least common multiple.
     ///
+    /// # Panics
+    /// Panics if overflow occured.
+    ///
     /// # Examples
     /// Basic usage:
     /// ```
@@ -66,8 +147,70 @@ pub trait NumberUtils {
     /// let n: u32 = 72;
     /// assert_eq!(n.lcm(10), 360);
     /// # }
+    /// ```
     fn lcm(&self, m: Self) -> Self;
 
+    /// Calculates greatest common divisor of all integers, which is zero if there are none.
+    ///
+    /// Stops reading the integers once the greatest common divisor reaches one.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// assert_eq!(u32::gcd_all([84, 120, 300]), 12);
+    /// assert_eq!(u32::gcd_all(vec![]), 0);
+    /// # }
+    /// ```
+    fn gcd_all<I: IntoIterator<Item = Self>>(ns: I) -> Self
+    where
+        Self: Sized;
+
+    /// Calculates least common multiple of all integers, which is one if there are none.
+    ///
+    /// # Panics
+    /// Panics if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// assert_eq!(u32::lcm_all(1..=10), 2520);
+    /// assert_eq!(u32::lcm_all([4, 0, 6]), 0);
+    /// # }
+    /// ```
+    fn lcm_all<I: IntoIterator<Item = Self>>(ns: I) -> Self
+    where
+        Self: Sized;
+
+    /// Calculates n-th Fibonacci number using fast doubling.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 10;
+    /// assert_eq!(n.fibonacci(), 55);
+    /// # }
+    /// ```
+    fn fibonacci(&self) -> Self;
+
+    /// Calculates n-th Lucas number using fast doubling.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 10;
+    /// assert_eq!(n.lucas(), 123);
+    /// # }
+    /// ```
+    fn lucas(&self) -> Self;
+
     /// Calculates factorial, returning None if overflow occured.
     ///
     /// # Examples
@@ -100,7 +243,8 @@ pub trait NumberUtils {
     where
         Self: Sized;
 
-    /// Calculates k-combination, returning None if overflow occured.
+    /// Calculates k-combination without intermediate overflow, returning None if the result
+    /// overflows.
     ///
     /// # Examples
     /// Basic usage:
@@ -116,37 +260,1004 @@ pub trait NumberUtils {
     where
         Self: Sized;
 
-    /// Calculates number of digits.
+    /// Calculates n-th Fibonacci number, returning None if overflow occured.
     ///
     /// # Examples
+    /// Basic usage:
     /// ```
     /// # use number_utils::NumberUtils;
     /// # fn main() {
-    /// let n: u32 = 1234567890;
-    /// assert_eq!(n.digits(), 10);
+    /// assert_eq!(93_u64.checked_fibonacci(), Some(12_200_160_415_121_876_738));
+    /// assert_eq!(94_u64.checked_fibonacci(), None);
     /// # }
     /// ```
-    fn digits(&self) -> Self;
-}
+    fn checked_fibonacci(&self) -> Option<Self>
+    where
+        Self: Sized;
 
-/// Implementation of the Pascal's triangle.
-///
-/// # Examples
-/// ```
-/// # use number_utils::pascals_triangle;
-/// # fn main() {
-/// assert_eq!(pascals_triangle(3), vec![1, 1, 1, 1, 2, 1]);
-/// # }
-/// ```
-pub fn pascals_triangle(n: usize) -> Vec<usize> {
-    let mut result = vec![1];
-    for i in 1..n {
-        let r = result.len() - i;
-        result.push(1);
-        for j in 1..i {
-            result.push(result[r + j - 1] + result[r + j]);
-        }
-        result.push(1);
-    }
-    result
+    /// Calculates n-th Lucas number, returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// assert_eq!(46_u32.checked_lucas(), Some(4_106_118_243));
+    /// assert_eq!(47_u32.checked_lucas(), None);
+    /// # }
+    /// ```
+    fn checked_lucas(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates double factorial, returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 7;
+    /// assert_eq!(n.checked_double_factorial(), Some(105));
+    /// assert_eq!(u32::MAX.checked_double_factorial(), None);
+    /// # }
+    /// ```
+    fn checked_double_factorial(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates least common multiple, returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 72;
+    /// assert_eq!(n.checked_lcm(10), Some(360));
+    /// assert_eq!(0_u32.checked_lcm(0), Some(0));
+    /// assert_eq!(65_536_u32.checked_lcm(65_537), None);
+    /// # }
+    /// ```
+    fn checked_lcm(&self, m: Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates least common multiple of all integers, returning None if overflow occured.
+    ///
+    /// The result is zero whenever one of the integers is zero, even if the others overflow.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// assert_eq!(u32::checked_lcm_all(1..=20), Some(232_792_560));
+    /// assert_eq!(u32::checked_lcm_all(1..=23), None);
+    /// assert_eq!(u32::checked_lcm_all((1..=23).chain([0])), Some(0));
+    /// # }
+    /// ```
+    fn checked_lcm_all<I: IntoIterator<Item = Self>>(ns: I) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates multinomial coefficient (k1 + k2 + ...)! / (k1! k2! ...), returning None if
+    /// overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// assert_eq!(u32::checked_multinomial(&[2, 3, 4]), Some(1_260));
+    /// assert_eq!(u32::checked_multinomial(&[20, 20]), None);
+    /// # }
+    /// ```
+    fn checked_multinomial(ks: &[Self]) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates the number of derangements !n, permutations of n elements without fixed
+    /// points, returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.checked_derangement(), Some(265));
+    /// assert_eq!(20_u32.checked_derangement(), None);
+    /// # }
+    /// ```
+    fn checked_derangement(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates rencontres number D(n, k), the number of permutations of n elements with
+    /// exactly k fixed points, returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.checked_rencontres(2), Some(135));
+    /// assert_eq!(20_u32.checked_rencontres(2), None);
+    /// # }
+    /// ```
+    fn checked_rencontres(&self, k: u32) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates n-th Catalan number, returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 10;
+    /// assert_eq!(n.checked_catalan(), Some(16_796));
+    /// assert_eq!(20_u32.checked_catalan(), None);
+    /// # }
+    /// ```
+    fn checked_catalan(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates unsigned Stirling number of the first kind, the number of permutations of n
+    /// elements with k cycles, returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.checked_stirling_first(3), Some(225));
+    /// assert_eq!(20_u32.checked_stirling_first(3), None);
+    /// # }
+    /// ```
+    fn checked_stirling_first(&self, k: u32) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates Stirling number of the second kind, the number of partitions of n elements
+    /// into k non-empty subsets, returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.checked_stirling_second(3), Some(90));
+    /// assert_eq!(30_u32.checked_stirling_second(3), None);
+    /// # }
+    /// ```
+    fn checked_stirling_second(&self, k: u32) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates n-th Bell number, returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.checked_bell(), Some(203));
+    /// assert_eq!(20_u32.checked_bell(), None);
+    /// # }
+    /// ```
+    fn checked_bell(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates unsigned Lah number, the number of partitions of n elements into k non-empty
+    /// linearly ordered subsets, returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.checked_lah(3), Some(1_200));
+    /// assert_eq!(20_u32.checked_lah(3), None);
+    /// # }
+    /// ```
+    fn checked_lah(&self, k: u32) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates Eulerian number, the number of permutations of n elements with k ascents,
+    /// returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.checked_eulerian(2), Some(302));
+    /// assert_eq!(20_u32.checked_eulerian(10), None);
+    /// # }
+    /// ```
+    fn checked_eulerian(&self, k: u32) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates the number of partitions p(n) using Euler's pentagonal number theorem,
+    /// returning None if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 100;
+    /// assert_eq!(n.checked_partitions(), Some(190_569_292));
+    /// assert_eq!(200_u32.checked_partitions(), None);
+    /// # }
+    /// ```
+    fn checked_partitions(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates the number of partitions into distinct parts, returning None if overflow
+    /// occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 100;
+    /// assert_eq!(n.checked_distinct_partitions(), Some(444_793));
+    /// # }
+    /// ```
+    fn checked_distinct_partitions(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates the number of partitions into at most k parts in O(nk) time, returning None if
+    /// overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 10;
+    /// assert_eq!(n.checked_partitions_at_most(3), Some(14));
+    /// # }
+    /// ```
+    fn checked_partitions_at_most(&self, k: u32) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates n-th Fibonacci number modulo `m` using fast doubling.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero. Use `try_fibonacci_mod` to get an error instead.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u64 = 1_000_000_000_000;
+    /// assert_eq!(n.fibonacci_mod(1_000_000_007), 730_695_249);
+    /// # }
+    /// ```
+    fn fibonacci_mod(&self, m: Self) -> Self;
+
+    /// Calculates the Pisano period, the period of the Fibonacci numbers modulo `self`, using
+    /// prime factorization by trial division. This takes O(√self) divisions, so about 2^32 for a
+    /// prime near `u64::MAX`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or the period overflows.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let m: u32 = 10;
+    /// assert_eq!(m.pisano_period(), 60);
+    /// # }
+    /// ```
+    fn pisano_period(&self) -> Self;
+
+    /// Calculates the Pisano period in O(√self) divisions, returning None if `self` is zero or
+    /// overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let m: u32 = 1_000_000_007;
+    /// assert_eq!(m.checked_pisano_period(), Some(2_000_000_016));
+    /// assert_eq!(0_u32.checked_pisano_period(), None);
+    /// # }
+    /// ```
+    fn checked_pisano_period(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates number of digits.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 1234567890;
+    /// assert_eq!(n.digits(), 10);
+    /// # }
+    /// ```
+    fn digits(&self) -> Self;
+
+    /// Calculates number of digits in the given base.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 255;
+    /// assert_eq!(n.digits_in(2), 8);
+    /// assert_eq!(n.digits_in(16), 2);
+    /// # }
+    /// ```
+    fn digits_in(&self, base: Self) -> Self;
+
+    /// Converts into digits in the given base, most significant digit first.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2 or greater than 256.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 1234;
+    /// assert_eq!(n.to_digits(10), vec![1, 2, 3, 4]);
+    /// assert_eq!(n.to_digits(16), vec![4, 13, 2]);
+    /// # }
+    /// ```
+    fn to_digits(&self, base: Self) -> Vec<u8>;
+
+    /// Converts digits in the given base, most significant digit first, returning None if a digit
+    /// is not less than `base` or overflow occured.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// assert_eq!(u32::from_digits(&[4, 13, 2], 16), Some(1234));
+    /// assert_eq!(u32::from_digits(&[1, 2], 2), None);
+    /// # }
+    /// ```
+    fn from_digits(digits: &[u8], base: Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Calculates sum of digits in the given base.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 1234;
+    /// assert_eq!(n.digit_sum(10), 10);
+    /// # }
+    /// ```
+    fn digit_sum(&self, base: Self) -> Self;
+
+    /// Calculates digital root, the result of repeatedly summing digits in the given base until a
+    /// single digit remains.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 1234;
+    /// assert_eq!(n.digital_root(10), 1);
+    /// # }
+    /// ```
+    fn digital_root(&self, base: Self) -> Self;
+
+    /// Reverses digits in the given base.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 1234;
+    /// assert_eq!(n.reverse_digits(10), 4321);
+    /// # }
+    /// ```
+    fn reverse_digits(&self, base: Self) -> Self;
+
+    /// Reverses digits in the given base, returning None if overflow occured.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 1234;
+    /// assert_eq!(n.checked_reverse_digits(10), Some(4321));
+    /// assert_eq!(4_000_000_009_u32.checked_reverse_digits(10), None);
+    /// # }
+    /// ```
+    fn checked_reverse_digits(&self, base: Self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Determines whether digits in the given base read the same backward as forward.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::NumberUtils;
+    /// # fn main() {
+    /// let n: u32 = 12321;
+    /// assert!(n.is_palindrome(10));
+    /// assert!(!n.is_palindrome(2));
+    /// # }
+    /// ```
+    fn is_palindrome(&self, base: Self) -> bool;
+
+    /// Calculates factorial, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 5;
+    /// assert_eq!(n.try_factorial(), Ok(120));
+    /// assert_eq!(u32::MAX.try_factorial(), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_factorial(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates k-permutations, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.try_permutation(3), Ok(120));
+    /// assert_eq!(u32::MAX.try_permutation(3), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_permutation(&self, k: u32) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates k-combination, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.try_combination(3), Ok(20));
+    /// assert_eq!(u32::MAX.try_combination(1200), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_combination(&self, k: u32) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates n-th Fibonacci number, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// assert_eq!(93_u64.try_fibonacci(), Ok(12_200_160_415_121_876_738));
+    /// assert_eq!(94_u64.try_fibonacci(), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_fibonacci(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates n-th Lucas number, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// assert_eq!(46_u32.try_lucas(), Ok(4_106_118_243));
+    /// assert_eq!(47_u32.try_lucas(), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_lucas(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates double factorial, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 7;
+    /// assert_eq!(n.try_double_factorial(), Ok(105));
+    /// assert_eq!(u32::MAX.try_double_factorial(), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_double_factorial(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates least common multiple, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 72;
+    /// assert_eq!(n.try_lcm(10), Ok(360));
+    /// assert_eq!(65_536_u32.try_lcm(65_537), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_lcm(&self, m: Self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates least common multiple of all integers, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// assert_eq!(u32::try_lcm_all(1..=20), Ok(232_792_560));
+    /// assert_eq!(u32::try_lcm_all(1..=23), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_lcm_all<I: IntoIterator<Item = Self>>(ns: I) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates multinomial coefficient (k1 + k2 + ...)! / (k1! k2! ...), returning an error if
+    /// overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// assert_eq!(u32::try_multinomial(&[2, 3, 4]), Ok(1_260));
+    /// assert_eq!(u32::try_multinomial(&[20, 20]), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_multinomial(ks: &[Self]) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates the number of derangements !n, permutations of n elements without fixed points,
+    /// returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.try_derangement(), Ok(265));
+    /// assert_eq!(20_u32.try_derangement(), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_derangement(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates rencontres number D(n, k), the number of permutations of n elements with exactly
+    /// k fixed points, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.try_rencontres(2), Ok(135));
+    /// assert_eq!(20_u32.try_rencontres(2), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_rencontres(&self, k: u32) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates n-th Catalan number, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 10;
+    /// assert_eq!(n.try_catalan(), Ok(16_796));
+    /// assert_eq!(20_u32.try_catalan(), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_catalan(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates unsigned Stirling number of the first kind, the number of permutations of n
+    /// elements with k cycles, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.try_stirling_first(3), Ok(225));
+    /// assert_eq!(20_u32.try_stirling_first(3), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_stirling_first(&self, k: u32) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates Stirling number of the second kind, the number of partitions of n elements into k
+    /// non-empty subsets, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.try_stirling_second(3), Ok(90));
+    /// assert_eq!(30_u32.try_stirling_second(3), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_stirling_second(&self, k: u32) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates n-th Bell number, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.try_bell(), Ok(203));
+    /// assert_eq!(20_u32.try_bell(), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_bell(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates unsigned Lah number, the number of partitions of n elements into k non-empty
+    /// linearly ordered subsets, returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.try_lah(3), Ok(1_200));
+    /// assert_eq!(20_u32.try_lah(3), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_lah(&self, k: u32) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates Eulerian number, the number of permutations of n elements with k ascents,
+    /// returning an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 6;
+    /// assert_eq!(n.try_eulerian(2), Ok(302));
+    /// assert_eq!(20_u32.try_eulerian(10), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_eulerian(&self, k: u32) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates the number of partitions p(n) using Euler's pentagonal number theorem, returning
+    /// an error if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 100;
+    /// assert_eq!(n.try_partitions(), Ok(190_569_292));
+    /// assert_eq!(200_u32.try_partitions(), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_partitions(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates the number of partitions into distinct parts, returning an error if overflow
+    /// occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 100;
+    /// assert_eq!(n.try_distinct_partitions(), Ok(444_793));
+    /// # }
+    /// ```
+    fn try_distinct_partitions(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates the number of partitions into at most k parts in O(nk) time, returning an error
+    /// if overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 10;
+    /// assert_eq!(n.try_partitions_at_most(3), Ok(14));
+    /// # }
+    /// ```
+    fn try_partitions_at_most(&self, k: u32) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates n-th Fibonacci number modulo `m`, returning an error if `m` is zero.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u64 = 1_000_000_000_000;
+    /// assert_eq!(n.try_fibonacci_mod(1_000_000_007), Ok(730_695_249));
+    /// assert_eq!(n.try_fibonacci_mod(0), Err(Error::InvalidModulus));
+    /// # }
+    /// ```
+    fn try_fibonacci_mod(&self, m: Self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Calculates the Pisano period in O(√self) divisions, returning an error if `self` is zero or
+    /// overflow occured.
+    ///
+    /// # Examples
+    /// Basic usage:
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let m: u32 = 1_000_000_007;
+    /// assert_eq!(m.try_pisano_period(), Ok(2_000_000_016));
+    /// assert_eq!(0_u32.try_pisano_period(), Err(Error::InvalidModulus));
+    /// assert_eq!(4_000_000_000_u32.try_pisano_period(), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_pisano_period(&self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Converts into digits in the given base, most significant digit first, returning an error
+    /// if `base` is less than 2 or greater than 256.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 1234;
+    /// assert_eq!(n.try_to_digits(16), Ok(vec![4, 13, 2]));
+    /// assert_eq!(n.try_to_digits(1), Err(Error::InvalidBase));
+    /// # }
+    /// ```
+    fn try_to_digits(&self, base: Self) -> Result<Vec<u8>, Error>;
+
+    /// Converts digits in the given base, most significant digit first, returning an error if
+    /// `base` is less than 2, a digit is not less than `base` or overflow occured.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// assert_eq!(u32::try_from_digits(&[4, 13, 2], 16), Ok(1234));
+    /// assert_eq!(u32::try_from_digits(&[1, 2], 2), Err(Error::InvalidInput));
+    /// assert_eq!(u32::try_from_digits(&[1; 33], 2), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_from_digits(digits: &[u8], base: Self) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Reverses digits in the given base, returning an error if `base` is less than 2 or overflow
+    /// occured.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::{Error, NumberUtils};
+    /// # fn main() {
+    /// let n: u32 = 1234;
+    /// assert_eq!(n.try_reverse_digits(10), Ok(4321));
+    /// assert_eq!(n.try_reverse_digits(0), Err(Error::InvalidBase));
+    /// assert_eq!(4_000_000_009_u32.try_reverse_digits(10), Err(Error::Overflow));
+    /// # }
+    /// ```
+    fn try_reverse_digits(&self, base: Self) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
+/// Implementation of the Pascal's triangle.
+///
+/// # Examples
+/// ```
+/// # use number_utils::pascals_triangle;
+/// # fn main() {
+/// assert_eq!(pascals_triangle(3), vec![1, 1, 1, 1, 2, 1]);
+/// # }
+/// ```
+pub fn pascals_triangle(n: usize) -> Vec<usize> {
+    let mut result = vec![1];
+    for i in 1..n {
+        let r = result.len() - i;
+        result.push(1);
+        for j in 1..i {
+            result.push(result[r + j - 1] + result[r + j]);
+        }
+        result.push(1);
+    }
+    result
+}
+
+/// Generates the first `n` rows of a triangle satisfying `T(0, 0) = 1` and
+/// `T(i, j) = a(i, j) T(i - 1, j) + b(i, j) T(i - 1, j - 1)`.
+fn triangle(
+    n: usize,
+    a: impl Fn(usize, usize) -> usize,
+    b: impl Fn(usize, usize) -> usize,
+) -> Vec<usize> {
+    let mut result = vec![];
+    if n == 0 {
+        return result;
+    }
+    result.push(1);
+    for i in 1..n {
+        let r = result.len() - i;
+        for j in 0..=i {
+            let above = if j < i { a(i, j) * result[r + j] } else { 0 };
+            let above_left = if j > 0 {
+                b(i, j) * result[r + j - 1]
+            } else {
+                0
+            };
+            result.push(above + above_left);
+        }
+    }
+    result
+}
+
+/// Implementation of the triangle of unsigned Stirling numbers of the first kind.
+///
+/// # Examples
+/// ```
+/// # use number_utils::stirling_first_triangle;
+/// # fn main() {
+/// assert_eq!(stirling_first_triangle(4), vec![1, 0, 1, 0, 1, 1, 0, 2, 3, 1]);
+/// # }
+/// ```
+pub fn stirling_first_triangle(n: usize) -> Vec<usize> {
+    triangle(n, |i, _| i - 1, |_, _| 1)
+}
+
+/// Implementation of the triangle of Stirling numbers of the second kind.
+///
+/// # Examples
+/// ```
+/// # use number_utils::stirling_second_triangle;
+/// # fn main() {
+/// assert_eq!(stirling_second_triangle(4), vec![1, 0, 1, 0, 1, 1, 0, 1, 3, 1]);
+/// # }
+/// ```
+pub fn stirling_second_triangle(n: usize) -> Vec<usize> {
+    triangle(n, |_, j| j, |_, _| 1)
+}
+
+/// Implementation of the Bell triangle.
+///
+/// # Examples
+/// ```
+/// # use number_utils::bell_triangle;
+/// # fn main() {
+/// assert_eq!(bell_triangle(4), vec![1, 1, 2, 2, 3, 5, 5, 7, 10, 15]);
+/// # }
+/// ```
+pub fn bell_triangle(n: usize) -> Vec<usize> {
+    let mut result = vec![];
+    if n == 0 {
+        return result;
+    }
+    result.push(1);
+    for i in 1..n {
+        let r = result.len() - i;
+        result.push(result[r + i - 1]);
+        for j in 0..i {
+            result.push(result[result.len() - 1] + result[r + j]);
+        }
+    }
+    result
+}
+
+/// Implementation of the triangle of unsigned Lah numbers.
+///
+/// # Examples
+/// ```
+/// # use number_utils::lah_triangle;
+/// # fn main() {
+/// assert_eq!(lah_triangle(4), vec![1, 0, 1, 0, 2, 1, 0, 6, 6, 1]);
+/// # }
+/// ```
+pub fn lah_triangle(n: usize) -> Vec<usize> {
+    triangle(n, |i, j| i - 1 + j, |_, _| 1)
+}
+
+/// Implementation of the triangle of Eulerian numbers.
+///
+/// # Examples
+/// ```
+/// # use number_utils::eulerian_triangle;
+/// # fn main() {
+/// assert_eq!(eulerian_triangle(4), vec![1, 1, 0, 1, 1, 0, 1, 4, 1, 0]);
+/// # }
+/// ```
+pub fn eulerian_triangle(n: usize) -> Vec<usize> {
+    triangle(n, |_, j| j + 1, |i, j| i - j)
 }
@@ -0,0 +1,143 @@
+use crate::UnsignedInteger;
+
+/// Calculates the k-th generalized pentagonal numbers `k(3k - 1) / 2` and `k(3k + 1) / 2`.
+fn pentagonal(k: usize) -> (usize, usize) {
+    (k * (3 * k - 1) / 2, k * (3 * k + 1) / 2)
+}
+
+/// Calculates `a(n)` for every n up to the given one from
+/// `a(n) = c(n) + Σ (-1)^(k + 1) (a(n - k(3k - 1) / 2) + a(n - k(3k + 1) / 2))`, returning None if
+/// overflow occured.
+///
+/// `a` has to be non-decreasing. The j-th added term is paired with the j-th subtracted term, whose
+/// offset is larger, so every partial sum is between zero and `a(n)`.
+fn checked_pentagonal_recurrence<T: UnsignedInteger>(
+    n: usize,
+    c: impl Fn(usize) -> i8,
+) -> Option<T> {
+    let mut table = vec![T::one()];
+    for i in 1..=n {
+        let (mut added, mut subtracted) = (vec![], vec![]);
+        for k in 1.. {
+            let (g1, g2) = pentagonal(k);
+            if g1 > i {
+                break;
+            }
+            let offsets = if (k & 1) == 1 {
+                &mut added
+            } else {
+                &mut subtracted
+            };
+            offsets.push(g1);
+            if g2 <= i {
+                offsets.push(g2);
+            }
+        }
+        let mut a = if c(i) == 1 { T::one() } else { T::zero() };
+        for (j, g) in added.into_iter().enumerate() {
+            let mut term = table[i - g].clone();
+            if let Some(h) = subtracted.get(j) {
+                term = term - table[i - h].clone();
+            }
+            a = a.checked_add(&term)?;
+        }
+        if c(i) == -1 {
+            a = a - T::one();
+        }
+        table.push(a);
+    }
+    table.pop()
+}
+
+/// Calculates the number of partitions, returning None if overflow occured.
+pub(crate) fn checked_partitions<T: UnsignedInteger>(n: &T) -> Option<T> {
+    checked_pentagonal_recurrence(n.to_usize()?, |_| 0)
+}
+
+/// Calculates the number of partitions into distinct parts, returning None if overflow occured.
+pub(crate) fn checked_distinct_partitions<T: UnsignedInteger>(n: &T) -> Option<T> {
+    // The generating function satisfies Q(x) E(x) = E(x^2), where E is the pentagonal number
+    // series, so n = j(3j ± 1) contributes (-1)^j.
+    checked_pentagonal_recurrence(n.to_usize()?, |i| {
+        if (i & 1) == 1 {
+            return 0;
+        }
+        for j in 1.. {
+            let (g1, g2) = pentagonal(j);
+            if 2 * g1 > i {
+                break;
+            }
+            if 2 * g1 == i || 2 * g2 == i {
+                return if (j & 1) == 1 { -1 } else { 1 };
+            }
+        }
+        0
+    })
+}
+
+/// Calculates the number of partitions into at most k parts, returning None if overflow occured.
+pub(crate) fn checked_partitions_at_most<T: UnsignedInteger>(n: &T, k: usize) -> Option<T> {
+    let n = n.to_usize()?;
+    let k = k.min(n);
+    if k == 0 {
+        return Some(if n == 0 { T::one() } else { T::zero() });
+    }
+    // Partitions into at most k parts are conjugate to partitions into parts at most k.
+    let mut table = vec![T::zero(); n + 1];
+    table[0] = T::one();
+    for part in 1..=k {
+        for i in part..=n {
+            table[i] = table[i].checked_add(&table[i - part])?;
+        }
+    }
+    table.pop()
+}
+
+/// An iterator over the partitions of n, with parts in non-increasing order, in lexicographic
+/// order.
+///
+/// # Examples
+/// ```
+/// # use number_utils::Partitions;
+/// # fn main() {
+/// assert_eq!(
+///     Partitions::new(4).collect::<Vec<_>>(),
+///     vec![vec![1, 1, 1, 1], vec![2, 1, 1], vec![2, 2], vec![3, 1], vec![4]]
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Partitions {
+    partition: Option<Vec<usize>>,
+}
+
+impl Partitions {
+    /// Creates a new iterator over the partitions of n.
+    pub fn new(n: usize) -> Self {
+        Self {
+            partition: Some(vec![1; n]),
+        }
+    }
+}
+
+impl Iterator for Partitions {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let partition = self.partition.take()?;
+        let mut next = partition.clone();
+        // Increments the rightmost part that can be incremented without breaking the order, then
+        // spreads the rest of the sum as ones.
+        for i in (0..next.len().saturating_sub(1)).rev() {
+            if i == 0 || next[i] < next[i - 1] {
+                let rest = next[i + 1..].iter().sum::<usize>() - 1;
+                next[i] += 1;
+                next.truncate(i + 1);
+                next.resize(i + 1 + rest, 1);
+                self.partition = Some(next);
+                break;
+            }
+        }
+        Some(partition)
+    }
+}
@@ -0,0 +1,102 @@
+/// Rearranges the slice into the next lexicographically greater permutation, returning false and
+/// rearranging it into the first permutation if it is already the last one.
+///
+/// # Examples
+/// ```
+/// # use number_utils::next_permutation;
+/// # fn main() {
+/// let mut v = [1, 2, 3];
+/// assert!(next_permutation(&mut v));
+/// assert_eq!(v, [1, 3, 2]);
+///
+/// let mut v = [3, 2, 1];
+/// assert!(!next_permutation(&mut v));
+/// assert_eq!(v, [1, 2, 3]);
+/// # }
+/// ```
+pub fn next_permutation<T: Ord>(v: &mut [T]) -> bool {
+    let Some(i) = (1..v.len()).rev().find(|&i| v[i - 1] < v[i]) else {
+        v.reverse();
+        return false;
+    };
+    let j = (i..v.len()).rev().find(|&j| v[i - 1] < v[j]).unwrap();
+    v.swap(i - 1, j);
+    v[i..].reverse();
+    true
+}
+
+/// Rearranges the slice into the previous lexicographically smaller permutation, returning false
+/// and rearranging it into the last permutation if it is already the first one.
+///
+/// # Examples
+/// ```
+/// # use number_utils::prev_permutation;
+/// # fn main() {
+/// let mut v = [1, 3, 2];
+/// assert!(prev_permutation(&mut v));
+/// assert_eq!(v, [1, 2, 3]);
+///
+/// let mut v = [1, 2, 3];
+/// assert!(!prev_permutation(&mut v));
+/// assert_eq!(v, [3, 2, 1]);
+/// # }
+/// ```
+pub fn prev_permutation<T: Ord>(v: &mut [T]) -> bool {
+    let Some(i) = (1..v.len()).rev().find(|&i| v[i - 1] > v[i]) else {
+        v.reverse();
+        return false;
+    };
+    let j = (i..v.len()).rev().find(|&j| v[i - 1] > v[j]).unwrap();
+    v.swap(i - 1, j);
+    v[i..].reverse();
+    true
+}
+
+/// An iterator over the k-permutations of a slice in lexicographic order of positions.
+///
+/// # Examples
+/// ```
+/// # use number_utils::Permutations;
+/// # fn main() {
+/// assert_eq!(
+///     Permutations::new(&[1, 2, 3], 2).collect::<Vec<_>>(),
+///     vec![vec![1, 2], vec![1, 3], vec![2, 1], vec![2, 3], vec![3, 1], vec![3, 2]]
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Permutations<T> {
+    pool: Vec<T>,
+    k: usize,
+    indices: Option<Vec<usize>>,
+}
+
+impl<T: Clone> Permutations<T> {
+    /// Creates a new iterator over the k-permutations of the slice.
+    pub fn new(pool: &[T], k: usize) -> Self {
+        Self {
+            pool: pool.to_vec(),
+            k,
+            indices: (k <= pool.len()).then(|| (0..pool.len()).collect()),
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let permutation = indices[..self.k]
+            .iter()
+            .map(|&i| self.pool[i].clone())
+            .collect();
+        // The unused indices are kept ascending, so reversing them makes the whole sequence the
+        // last one with the current prefix.
+        indices[self.k..].reverse();
+        if !next_permutation(indices) {
+            self.indices = None;
+        }
+        Some(permutation)
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{combinatorics::checked_binomial, Error};
+
+/// Checks whether the slice is a strictly increasing sequence of integers less than n.
+pub(crate) fn is_combination(combination: &[usize], n: usize) -> bool {
+    combination.windows(2).all(|w| w[0] < w[1]) && combination.iter().all(|&c| c < n)
+}
+
+/// Calculates the Lehmer code of a permutation of `0..n`, returning None if the slice is not a
+/// permutation.
+pub(crate) fn lehmer_code(permutation: &[usize]) -> Option<Vec<usize>> {
+    let n = permutation.len();
+    let mut seen = vec![false; n];
+    for &p in permutation {
+        if p >= n || seen[p] {
+            return None;
+        }
+        seen[p] = true;
+    }
+    Some(
+        (0..n)
+            .map(|i| {
+                permutation[i + 1..]
+                    .iter()
+                    .filter(|&&p| p < permutation[i])
+                    .count()
+            })
+            .collect(),
+    )
+}
+
+/// Converts a Lehmer code into the permutation of `0..n` it encodes.
+pub(crate) fn from_lehmer_code(code: &[usize]) -> Vec<usize> {
+    let mut elements = (0..code.len()).collect::<Vec<_>>();
+    code.iter().map(|&d| elements.remove(d)).collect()
+}
+
+/// Calculates the rank of a k-combination of `0..n` in lexicographic order using the
+/// combinatorial number system, returning None if the combination is not strictly increasing,
+/// contains an integer not less than n or overflow occured.
+///
+/// # Examples
+/// ```
+/// # use number_utils::rank_combination;
+/// # fn main() {
+/// assert_eq!(rank_combination(&[0, 1], 4), Some(0));
+/// assert_eq!(rank_combination(&[1, 3], 4), Some(4));
+/// assert_eq!(rank_combination(&[3, 1], 4), None);
+/// # }
+/// ```
+pub fn rank_combination(combination: &[usize], n: usize) -> Option<usize> {
+    if !is_combination(combination, n) {
+        return None;
+    }
+    let k = combination.len();
+    let mut rank = checked_binomial(&n, &k)? - 1;
+    for (i, &c) in combination.iter().enumerate() {
+        rank -= checked_binomial(&(n - 1 - c), &(k - i))?;
+    }
+    Some(rank)
+}
+
+/// Calculates the k-combination of `0..n` with the given rank in lexicographic order, returning
+/// None if the rank is out of range or overflow occured.
+///
+/// # Examples
+/// ```
+/// # use number_utils::unrank_combination;
+/// # fn main() {
+/// assert_eq!(unrank_combination(4, 4, 2), Some(vec![1, 3]));
+/// assert_eq!(unrank_combination(6, 4, 2), None);
+/// # }
+/// ```
+pub fn unrank_combination(rank: usize, n: usize, k: usize) -> Option<Vec<usize>> {
+    let count = checked_binomial(&n, &k)?;
+    if rank >= count {
+        return None;
+    }
+    let mut rest = count - 1 - rank;
+    let mut m = n;
+    let mut combination = Vec::with_capacity(k);
+    for i in 0..k {
+        // Finds the largest m with C(m, k - i) <= rest.
+        m -= 1;
+        let mut binomial = checked_binomial(&m, &(k - i))?;
+        while binomial > rest {
+            m -= 1;
+            binomial = checked_binomial(&m, &(k - i))?;
+        }
+        rest -= binomial;
+        combination.push(n - 1 - m);
+    }
+    Some(combination)
+}
+
+/// Calculates the rank of a permutation of `0..n` in lexicographic order using the factorial
+/// number system, returning None if the slice is not a permutation or overflow occured.
+///
+/// # Examples
+/// ```
+/// # use number_utils::rank_permutation;
+/// # fn main() {
+/// assert_eq!(rank_permutation(&[0, 1, 2]), Some(0));
+/// assert_eq!(rank_permutation(&[1, 2, 0]), Some(3));
+/// assert_eq!(rank_permutation(&[1, 1, 0]), None);
+/// # }
+/// ```
+pub fn rank_permutation(permutation: &[usize]) -> Option<usize> {
+    let n = permutation.len();
+    lehmer_code(permutation)?
+        .iter()
+        .enumerate()
+        .try_fold(0_usize, |rank, (i, &d)| {
+            rank.checked_mul(n - i)?.checked_add(d)
+        })
+}
+
+/// Calculates the permutation of `0..n` with the given rank in lexicographic order, returning
+/// None if the rank is out of range.
+///
+/// # Examples
+/// ```
+/// # use number_utils::unrank_permutation;
+/// # fn main() {
+/// assert_eq!(unrank_permutation(3, 3), Some(vec![1, 2, 0]));
+/// assert_eq!(unrank_permutation(6, 3), None);
+/// # }
+/// ```
+pub fn unrank_permutation(mut rank: usize, n: usize) -> Option<Vec<usize>> {
+    let mut code = vec![0; n];
+    for i in (0..n).rev() {
+        code[i] = rank % (n - i);
+        rank /= n - i;
+    }
+    if rank != 0 {
+        return None;
+    }
+    Some(from_lehmer_code(&code))
+}
+
+/// Calculates the rank of a k-combination of `0..n` in lexicographic order, returning an error if
+/// the combination is not strictly increasing, contains an integer not less than n or overflow
+/// occured.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_rank_combination, Error};
+/// # fn main() {
+/// assert_eq!(try_rank_combination(&[1, 3], 4), Ok(4));
+/// assert_eq!(try_rank_combination(&[3, 1], 4), Err(Error::InvalidInput));
+/// assert_eq!(try_rank_combination(&(0..40).collect::<Vec<_>>(), 200), Err(Error::Overflow));
+/// # }
+/// ```
+pub fn try_rank_combination(combination: &[usize], n: usize) -> Result<usize, Error> {
+    if !is_combination(combination, n) {
+        return Err(Error::InvalidInput);
+    }
+    rank_combination(combination, n).ok_or(Error::Overflow)
+}
+
+/// Calculates the k-combination of `0..n` with the given rank in lexicographic order, returning
+/// an error if the rank is out of range or overflow occured.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_unrank_combination, Error};
+/// # fn main() {
+/// assert_eq!(try_unrank_combination(4, 4, 2), Ok(vec![1, 3]));
+/// assert_eq!(try_unrank_combination(6, 4, 2), Err(Error::InvalidInput));
+/// # }
+/// ```
+pub fn try_unrank_combination(rank: usize, n: usize, k: usize) -> Result<Vec<usize>, Error> {
+    let count = checked_binomial(&n, &k).ok_or(Error::Overflow)?;
+    if rank >= count {
+        return Err(Error::InvalidInput);
+    }
+    unrank_combination(rank, n, k).ok_or(Error::Overflow)
+}
+
+/// Calculates the rank of a permutation of `0..n` in lexicographic order, returning an error if
+/// the slice is not a permutation or overflow occured.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_rank_permutation, Error};
+/// # fn main() {
+/// assert_eq!(try_rank_permutation(&[1, 2, 0]), Ok(3));
+/// assert_eq!(try_rank_permutation(&[1, 1, 0]), Err(Error::InvalidInput));
+/// assert_eq!(try_rank_permutation(&(0..30).rev().collect::<Vec<_>>()), Err(Error::Overflow));
+/// # }
+/// ```
+pub fn try_rank_permutation(permutation: &[usize]) -> Result<usize, Error> {
+    if lehmer_code(permutation).is_none() {
+        return Err(Error::InvalidInput);
+    }
+    rank_permutation(permutation).ok_or(Error::Overflow)
+}
+
+/// Calculates the permutation of `0..n` with the given rank in lexicographic order, returning an
+/// error if the rank is out of range.
+///
+/// # Examples
+/// ```
+/// # use number_utils::{try_unrank_permutation, Error};
+/// # fn main() {
+/// assert_eq!(try_unrank_permutation(3, 3), Ok(vec![1, 2, 0]));
+/// assert_eq!(try_unrank_permutation(6, 3), Err(Error::InvalidInput));
+/// # }
+/// ```
+pub fn try_unrank_permutation(rank: usize, n: usize) -> Result<Vec<usize>, Error> {
+    unrank_permutation(rank, n).ok_or(Error::InvalidInput)
+}
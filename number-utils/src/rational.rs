@@ -0,0 +1,314 @@
+use crate::{lehmer, Error};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// An exact rational number, always kept in lowest terms with a positive denominator.
+///
+/// # Examples
+/// ```
+/// # use number_utils::Rational;
+/// # fn main() {
+/// let x = Rational::new(6, -4);
+/// assert_eq!(x.to_string(), "-3/2");
+/// assert_eq!(&x + &Rational::new(1, 2), Rational::from(-1));
+/// assert_eq!(x.recip() * Rational::new(3, 1), Rational::new(-2, 1));
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: BigInt,
+    denom: BigInt,
+}
+
+impl Rational {
+    /// Creates `numer / denom` in lowest terms.
+    ///
+    /// # Panics
+    /// Panics if `denom` is zero.
+    pub fn new<T: Into<BigInt>, U: Into<BigInt>>(numer: T, denom: U) -> Self {
+        Self::try_new(numer, denom).expect("denominator must not be zero")
+    }
+
+    /// Creates `numer / denom` in lowest terms, returning an error if `denom` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::{Error, Rational};
+    /// # fn main() {
+    /// assert_eq!(Rational::try_new(2, 4), Ok(Rational::new(1, 2)));
+    /// assert_eq!(Rational::try_new(1, 0), Err(Error::InvalidInput));
+    /// # }
+    /// ```
+    pub fn try_new<T: Into<BigInt>, U: Into<BigInt>>(numer: T, denom: U) -> Result<Self, Error> {
+        let (numer, denom) = (numer.into(), denom.into());
+        if denom.is_zero() {
+            return Err(Error::InvalidInput);
+        }
+        Ok(Self::reduce(numer, denom))
+    }
+
+    /// Reduces `numer / denom` with nonzero `denom` to lowest terms using `gcd_big`.
+    fn reduce(numer: BigInt, denom: BigInt) -> Self {
+        let (numer_sign, numer) = numer.into_parts();
+        let (denom_sign, denom) = denom.into_parts();
+        let gcd = lehmer::gcd(numer.clone(), denom.clone());
+        let sign = match (numer_sign, denom_sign) {
+            (Sign::NoSign, _) => Sign::NoSign,
+            (numer_sign, denom_sign) if numer_sign == denom_sign => Sign::Plus,
+            _ => Sign::Minus,
+        };
+        Rational {
+            numer: BigInt::from_biguint(sign, numer / &gcd),
+            denom: BigInt::from(denom / gcd),
+        }
+    }
+
+    /// Returns the numerator.
+    pub fn numer(&self) -> &BigInt {
+        &self.numer
+    }
+
+    /// Returns the denominator, which is always positive.
+    pub fn denom(&self) -> &BigInt {
+        &self.denom
+    }
+
+    /// Checks whether the denominator is one.
+    pub fn is_integer(&self) -> bool {
+        self.denom.is_one()
+    }
+
+    /// Calculates the absolute value.
+    pub fn abs(&self) -> Self {
+        Rational {
+            numer: self.numer.abs(),
+            denom: self.denom.clone(),
+        }
+    }
+
+    /// Calculates the reciprocal.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    pub fn recip(&self) -> Self {
+        Self::new(self.denom.clone(), self.numer.clone())
+    }
+
+    /// Calculates the nearest `f64`, rounding half to even, which is infinite or zero if out of
+    /// range.
+    ///
+    /// # Examples
+    /// ```
+    /// # use number_utils::Rational;
+    /// # fn main() {
+    /// assert_eq!(Rational::new(-3, 8).to_f64(), -0.375);
+    /// assert_eq!(Rational::new(1, 10).to_f64(), 0.1);
+    /// # }
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        let (numer, denom) = (self.numer.magnitude(), self.denom.magnitude());
+        if numer.is_zero() {
+            return 0.0;
+        }
+        // Quotient scaled by 2^shift, with 54 or 55 significant bits.
+        let quotient = |shift: i64| {
+            if shift >= 0 {
+                (numer << shift as u64).div_rem(denom)
+            } else {
+                numer.div_rem(&(denom << shift.unsigned_abs()))
+            }
+        };
+        let mut shift = 54 + denom.bits() as i64 - numer.bits() as i64;
+        let (mut q, mut r) = quotient(shift);
+        // Drop to the fixed resolution 2^-1074 below the smallest normal exponent.
+        let drop = if q.bits() as i64 - 1 - shift < -1022 {
+            shift = 1075;
+            (q, r) = quotient(shift);
+            1
+        } else {
+            q.bits() - 53
+        };
+        let half = q.bit(drop - 1);
+        let sticky = !r.is_zero() || q.trailing_zeros().is_some_and(|zeros| zeros < drop - 1);
+        let mut mantissa = (q >> drop).to_u64().unwrap_or(0);
+        if half && (sticky || mantissa % 2 == 1) {
+            mantissa += 1;
+        }
+        let exp = (drop as i64 - shift).clamp(-2000, 2000) as i32;
+        // Split the power of two so that neither factor overflows before the result does.
+        let abs = mantissa as f64 * 2_f64.powi(exp / 2) * 2_f64.powi(exp - exp / 2);
+        if self.numer.is_negative() {
+            -abs
+        } else {
+            abs
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(n: BigInt) -> Self {
+        Rational {
+            numer: n,
+            denom: BigInt::one(),
+        }
+    }
+}
+
+impl From<BigUint> for Rational {
+    fn from(n: BigUint) -> Self {
+        Self::from(BigInt::from(n))
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self::from(BigInt::from(n))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.numer * &other.denom).cmp(&(&other.numer * &self.denom))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        -self.clone()
+    }
+}
+
+impl Add<&Rational> for &Rational {
+    type Output = Rational;
+
+    fn add(self, other: &Rational) -> Rational {
+        Rational::reduce(
+            &self.numer * &other.denom + &other.numer * &self.denom,
+            &self.denom * &other.denom,
+        )
+    }
+}
+
+impl Sub<&Rational> for &Rational {
+    type Output = Rational;
+
+    fn sub(self, other: &Rational) -> Rational {
+        Rational::reduce(
+            &self.numer * &other.denom - &other.numer * &self.denom,
+            &self.denom * &other.denom,
+        )
+    }
+}
+
+impl Mul<&Rational> for &Rational {
+    type Output = Rational;
+
+    fn mul(self, other: &Rational) -> Rational {
+        Rational::reduce(&self.numer * &other.numer, &self.denom * &other.denom)
+    }
+}
+
+impl Div<&Rational> for &Rational {
+    type Output = Rational;
+
+    /// # Panics
+    /// Panics if `other` is zero.
+    fn div(self, other: &Rational) -> Rational {
+        Rational::new(&self.numer * &other.denom, &self.denom * &other.numer)
+    }
+}
+
+/// Implements an operator for owned operands by forwarding to the implementation for references.
+macro_rules! forward_binop {
+    ($($trait:ident $method:ident),*) => {$(
+        impl $trait<Rational> for Rational {
+            type Output = Rational;
+
+            fn $method(self, other: Rational) -> Rational {
+                (&self).$method(&other)
+            }
+        }
+
+        impl $trait<&Rational> for Rational {
+            type Output = Rational;
+
+            fn $method(self, other: &Rational) -> Rational {
+                (&self).$method(other)
+            }
+        }
+
+        impl $trait<Rational> for &Rational {
+            type Output = Rational;
+
+            fn $method(self, other: Rational) -> Rational {
+                self.$method(&other)
+            }
+        }
+    )*};
+}
+
+forward_binop!(Add add, Sub sub, Mul mul, Div div);
+
+impl Zero for Rational {
+    fn zero() -> Self {
+        Self::from(BigInt::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+}
+
+impl One for Rational {
+    fn one() -> Self {
+        Self::from(BigInt::one())
+    }
+}
+
+impl Sum for Rational {
+    fn sum<I: Iterator<Item = Rational>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, x| sum + x)
+    }
+}
+
+impl Product for Rational {
+    fn product<I: Iterator<Item = Rational>>(iter: I) -> Self {
+        iter.fold(Self::one(), |product, x| product * x)
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::*;
-    use num_bigint::ToBigUint;
+    use num_bigint::{ToBigInt, ToBigUint};
 
     #[test]
     fn test_factorial() {
@@ -16,6 +16,39 @@ mod tests {
         assert_eq!(factorial_big(6), 720.to_biguint());
     }
 
+    #[test]
+    fn test_double_factorial() {
+        assert_eq!(7_u32.double_factorial(), 105);
+        assert_eq!(7_u64.double_factorial(), 105);
+        assert_eq!(7_u128.double_factorial(), 105);
+        assert_eq!(7_usize.double_factorial(), 105);
+        assert_eq!(7_u32.checked_double_factorial(), Some(105));
+        assert_eq!(7_u64.checked_double_factorial(), Some(105));
+        assert_eq!(7_u128.checked_double_factorial(), Some(105));
+        assert_eq!(7_usize.checked_double_factorial(), Some(105));
+        assert_eq!(double_factorial_big(7), 105.to_biguint());
+        assert_eq!(
+            (0_u32..10)
+                .map(|n| n.double_factorial())
+                .collect::<Vec<_>>(),
+            vec![1, 1, 2, 3, 8, 15, 48, 105, 384, 945]
+        );
+        for n in 0_u64..40 {
+            assert_eq!(
+                n.checked_double_factorial(),
+                double_factorial_big(n).and_then(|x| x.try_into().ok())
+            );
+            if n > 0 {
+                assert_eq!(
+                    double_factorial_big(n)
+                        .zip(double_factorial_big(n - 1))
+                        .map(|(a, b)| a * b),
+                    factorial_big(n)
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_permutation() {
         assert_eq!(6_u32.permutation(3), 120);
@@ -34,6 +67,112 @@ mod tests {
         assert_eq!(combination_big(6, 3), 20.to_biguint());
     }
 
+    #[test]
+    fn test_multinomial() {
+        assert_eq!(u32::checked_multinomial(&[2, 3, 4]), Some(1_260));
+        assert_eq!(u64::checked_multinomial(&[2, 3, 4]), Some(1_260));
+        assert_eq!(u128::checked_multinomial(&[2, 3, 4]), Some(1_260));
+        assert_eq!(usize::checked_multinomial(&[2, 3, 4]), Some(1_260));
+        assert_eq!(multinomial_big(&[2, 3, 4]), 1_260.to_biguint());
+        assert_eq!(u32::checked_multinomial(&[]), Some(1));
+        assert_eq!(multinomial_big::<u32>(&[]), 1.to_biguint());
+        for n in 0_u64..20 {
+            for k in 0..=n {
+                assert_eq!(
+                    u64::checked_multinomial(&[k, n - k]),
+                    n.checked_combination(k as u32)
+                );
+            }
+        }
+        assert_eq!(
+            u64::checked_multinomial(&[33, 34]),
+            Some(14_226_520_737_620_288_370)
+        );
+        assert_eq!(
+            60_u64.checked_combination(30),
+            u64::checked_multinomial(&[30, 30])
+        );
+        assert_eq!(
+            60_u64.checked_combination(30),
+            Some(118_264_581_564_861_424)
+        );
+        assert_eq!(
+            67_u64.checked_combination(33),
+            Some(14_226_520_737_620_288_370)
+        );
+        assert_eq!(68_u64.checked_combination(34), None);
+        assert_eq!(u64::MAX.checked_combination(0), Some(1));
+        assert_eq!(u64::MAX.checked_combination(1), Some(u64::MAX));
+        assert_eq!(u64::MAX.checked_combination(2), None);
+        assert_eq!(
+            (u32::MAX as u64 + 2).checked_combination(2),
+            Some(9_223_372_039_002_259_456)
+        );
+        assert_eq!(
+            u128::from(u64::MAX).checked_combination(2),
+            Some(170_141_183_460_469_231_704_017_187_605_319_778_305)
+        );
+        assert_eq!(
+            131_u128.checked_combination(65),
+            Some(188_694_833_082_770_476_622_296_176_145_946_360_850)
+        );
+        assert_eq!(132_u128.checked_combination(66), None);
+        assert_eq!(u128::MAX.checked_combination(1), Some(u128::MAX));
+        assert_eq!(u128::MAX.checked_combination(2), None);
+        assert_eq!(5_u64.checked_combination(6), Some(0));
+        assert_eq!(u64::checked_multinomial(&[34, 34]), None);
+        for k in 15..25 {
+            assert_eq!(
+                u128::checked_multinomial(&[k, k, k]),
+                multinomial_big(&[k, k, k]).and_then(|x| x.try_into().ok())
+            );
+        }
+    }
+
+    #[test]
+    fn test_derangement() {
+        assert_eq!(6_u32.checked_derangement(), Some(265));
+        assert_eq!(6_u64.checked_derangement(), Some(265));
+        assert_eq!(6_u128.checked_derangement(), Some(265));
+        assert_eq!(6_usize.checked_derangement(), Some(265));
+        assert_eq!(derangement_big(6), 265.to_biguint());
+        assert_eq!(
+            (0_u32..8)
+                .map(|n| n.checked_derangement().unwrap())
+                .collect::<Vec<_>>(),
+            vec![1, 0, 1, 2, 9, 44, 265, 1854]
+        );
+        for n in 0_u64..25 {
+            assert_eq!(
+                n.checked_derangement(),
+                derangement_big(n).and_then(|x| x.try_into().ok())
+            );
+        }
+    }
+
+    #[test]
+    fn test_rencontres() {
+        assert_eq!(6_u32.checked_rencontres(2), Some(135));
+        assert_eq!(6_u64.checked_rencontres(2), Some(135));
+        assert_eq!(6_u128.checked_rencontres(2), Some(135));
+        assert_eq!(6_usize.checked_rencontres(2), Some(135));
+        assert_eq!(rencontres_big(6, 2), 135.to_biguint());
+        for n in 0_u64..20 {
+            let rencontres_sum = (0..=n as u32)
+                .map(|k| n.checked_rencontres(k).unwrap())
+                .sum();
+            assert_eq!(n.checked_factorial(), Some(rencontres_sum));
+            assert_eq!(n.checked_rencontres(0), n.checked_derangement());
+            assert_eq!(n.checked_rencontres(n as u32 + 1), Some(0));
+            for k in 0..=n {
+                assert_eq!(
+                    n.checked_rencontres(k as u32),
+                    rencontres_big(n, k).and_then(|x| x.try_into().ok())
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_gcd() {
         assert_eq!(54_u32.gcd(24), 6);
@@ -41,6 +180,17 @@ mod tests {
         assert_eq!(54_u128.gcd(24), 6);
         assert_eq!(54_usize.gcd(24), 6);
         assert_eq!(gcd_big(54, 24), 6.to_biguint());
+        assert_eq!(u64::gcd_all([54, 24, 81]), 3);
+        assert_eq!(u64::gcd_all([0, 0]), 0);
+        // The iterator is not read after the gcd reaches one.
+        assert_eq!(
+            u64::gcd_all([2, 3].into_iter().chain(std::iter::repeat(4))),
+            1
+        );
+        assert_eq!(
+            num_bigint::BigUint::gcd_all([54, 24].map(|n| n.to_biguint().unwrap())),
+            6.to_biguint().unwrap()
+        );
     }
 
     #[test]
@@ -50,6 +200,1024 @@ mod tests {
         assert_eq!(72_u128.lcm(10), 360);
         assert_eq!(72_usize.lcm(10), 360);
         assert_eq!(lcm_big(72, 10), 360.to_biguint());
+        assert_eq!(72_u32.checked_lcm(10), Some(360));
+        assert_eq!(0_u32.checked_lcm(10), Some(0));
+        assert_eq!(u32::MAX.checked_lcm(2), None);
+        assert_eq!(u32::MAX.try_lcm(2), Err(Error::Overflow));
+        assert_eq!(u64::lcm_all([4, 6, 10]), 60);
+        assert_eq!(u64::lcm_all(vec![]), 1);
+        assert_eq!(u64::checked_lcm_all(1..=42), Some(219_060_189_739_591_200));
+        assert_eq!(u64::checked_lcm_all(1..=47), None);
+        assert_eq!(u64::checked_lcm_all((0..=47).rev()), Some(0));
+        assert_eq!(u64::try_lcm_all(1..=47), Err(Error::Overflow));
+        assert_eq!(
+            num_bigint::BigUint::lcm_all((1_u32..=43).map(|n| n.to_biguint().unwrap())),
+            lcm_all_big(1..=43).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fibonacci() {
+        assert_eq!(10_u32.fibonacci(), 55);
+        assert_eq!(10_u64.fibonacci(), 55);
+        assert_eq!(10_u128.fibonacci(), 55);
+        assert_eq!(10_usize.fibonacci(), 55);
+        assert_eq!(
+            (0_u32..20).map(|n| n.fibonacci()).collect::<Vec<_>>(),
+            vec![
+                0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610, 987, 1597, 2584, 4181
+            ]
+        );
+        assert_eq!(47_u32.checked_fibonacci(), Some(2_971_215_073));
+        assert_eq!(48_u32.checked_fibonacci(), None);
+        assert_eq!(93_u64.checked_fibonacci(), Some(12_200_160_415_121_876_738));
+        assert_eq!(94_u64.checked_fibonacci(), None);
+        assert_eq!(
+            186_u128.checked_fibonacci(),
+            Some(332_825_110_087_067_562_321_196_029_789_634_457_848)
+        );
+        assert_eq!(187_u128.checked_fibonacci(), None);
+        assert_eq!(fibonacci_big(186), 186_u128.fibonacci().to_biguint());
+        assert_eq!(fibonacci_big(1000).map(|n| n.to_string().len()), Some(209));
+    }
+
+    #[test]
+    fn test_lucas() {
+        assert_eq!(10_u32.lucas(), 123);
+        assert_eq!(10_u64.lucas(), 123);
+        assert_eq!(10_u128.lucas(), 123);
+        assert_eq!(10_usize.lucas(), 123);
+        assert_eq!(
+            (0_u32..10).map(|n| n.lucas()).collect::<Vec<_>>(),
+            vec![2, 1, 3, 4, 7, 11, 18, 29, 47, 76]
+        );
+        assert_eq!(46_u32.checked_lucas(), Some(4_106_118_243));
+        assert_eq!(47_u32.checked_lucas(), None);
+        assert_eq!(lucas_big(0), 2.to_biguint());
+        assert_eq!(lucas_big(10), 123.to_biguint());
+    }
+
+    #[test]
+    fn test_fibonacci_mod() {
+        assert_eq!(100_u32.fibonacci_mod(1_000), 75);
+        assert_eq!(100_u64.fibonacci_mod(1_000), 75);
+        assert_eq!(100_u128.fibonacci_mod(1_000), 75);
+        assert_eq!(100_usize.fibonacci_mod(1_000), 75);
+        assert_eq!(100_u32.fibonacci_mod(1), 0);
+        assert_eq!(
+            u128::MAX.fibonacci_mod(u128::MAX - 1),
+            fibonacci_mod_big(u128::MAX, u128::MAX - 1)
+                .and_then(|n| n.try_into().ok())
+                .unwrap()
+        );
+        assert_eq!(fibonacci_mod_big(100, 1_000), 75.to_biguint());
+        assert_eq!(fibonacci_mod_big(100, 0), None);
+    }
+
+    #[test]
+    fn test_pisano_period() {
+        assert_eq!(10_u32.pisano_period(), 60);
+        assert_eq!(10_u64.pisano_period(), 60);
+        assert_eq!(10_u128.pisano_period(), 60);
+        assert_eq!(10_usize.pisano_period(), 60);
+        assert_eq!(
+            (1_u32..=12).map(|m| m.pisano_period()).collect::<Vec<_>>(),
+            vec![1, 3, 8, 6, 20, 24, 16, 12, 24, 60, 10, 24]
+        );
+        assert_eq!(1_000_000_u64.pisano_period(), 1_500_000);
+        assert_eq!(0_u32.checked_pisano_period(), None);
+        assert_eq!(4_000_000_000_u32.checked_pisano_period(), None);
+    }
+
+    #[test]
+    fn test_catalan() {
+        assert_eq!(10_u32.checked_catalan(), Some(16_796));
+        assert_eq!(10_u64.checked_catalan(), Some(16_796));
+        assert_eq!(10_u128.checked_catalan(), Some(16_796));
+        assert_eq!(10_usize.checked_catalan(), Some(16_796));
+        assert_eq!(catalan_big(10), 16_796.to_biguint());
+        for n in 0_u128..=70 {
+            assert_eq!(
+                n.checked_catalan(),
+                catalan_big(n).and_then(|x| x.try_into().ok())
+            );
+        }
+        assert_eq!(70_u128.checked_catalan(), None);
+    }
+
+    #[test]
+    fn test_stirling() {
+        assert_eq!(6_u32.checked_stirling_first(3), Some(225));
+        assert_eq!(6_u64.checked_stirling_first(3), Some(225));
+        assert_eq!(6_u128.checked_stirling_first(3), Some(225));
+        assert_eq!(6_usize.checked_stirling_first(3), Some(225));
+        assert_eq!(6_u32.checked_stirling_second(3), Some(90));
+        assert_eq!(6_u64.checked_stirling_second(3), Some(90));
+        assert_eq!(6_u128.checked_stirling_second(3), Some(90));
+        assert_eq!(6_usize.checked_stirling_second(3), Some(90));
+        assert_eq!(stirling_first_big(6, 3), 225.to_biguint());
+        assert_eq!(stirling_second_big(6, 3), 90.to_biguint());
+        for n in 0_u64..25 {
+            for k in 0..=n as u32 + 1 {
+                assert_eq!(
+                    n.checked_stirling_first(k),
+                    stirling_first_big(n, k as u64).and_then(|x| x.try_into().ok())
+                );
+                assert_eq!(
+                    n.checked_stirling_second(k),
+                    stirling_second_big(n, k as u64).and_then(|x| x.try_into().ok())
+                );
+            }
+        }
+        assert_eq!(1_000_000_000_u64.checked_stirling_second(1), Some(1));
+        assert_eq!(
+            1_000_000_u64.checked_stirling_first(999_999),
+            1_000_000_u64.checked_combination(2)
+        );
+        assert_eq!(30_u32.checked_stirling_second(3), None);
+    }
+
+    #[test]
+    fn test_bell() {
+        assert_eq!(6_u32.checked_bell(), Some(203));
+        assert_eq!(6_u64.checked_bell(), Some(203));
+        assert_eq!(6_u128.checked_bell(), Some(203));
+        assert_eq!(6_usize.checked_bell(), Some(203));
+        assert_eq!(bell_big(6), 203.to_biguint());
+        for n in 0_u32..15 {
+            let stirling_sum = (0..=n).map(|k| n.checked_stirling_second(k).unwrap()).sum();
+            assert_eq!(n.checked_bell(), Some(stirling_sum));
+        }
+        assert_eq!(16_u32.checked_bell(), None);
+    }
+
+    #[test]
+    fn test_lah() {
+        assert_eq!(6_u32.checked_lah(3), Some(1_200));
+        assert_eq!(6_u64.checked_lah(3), Some(1_200));
+        assert_eq!(6_u128.checked_lah(3), Some(1_200));
+        assert_eq!(6_usize.checked_lah(3), Some(1_200));
+        assert_eq!(lah_big(6, 3), 1_200.to_biguint());
+        for n in 1_u128..20 {
+            for k in 1..=n as u32 {
+                let lah = (n - 1).combination(k - 1) * n.factorial() / (k as u128).factorial();
+                assert_eq!(n.checked_lah(k), Some(lah));
+            }
+        }
+    }
+
+    #[test]
+    fn test_eulerian() {
+        assert_eq!(6_u32.checked_eulerian(2), Some(302));
+        assert_eq!(6_u64.checked_eulerian(2), Some(302));
+        assert_eq!(6_u128.checked_eulerian(2), Some(302));
+        assert_eq!(6_usize.checked_eulerian(2), Some(302));
+        assert_eq!(eulerian_big(6, 2), 302.to_biguint());
+        for n in 0_u64..20 {
+            let eulerian_sum = (0..=n as u32).map(|k| n.checked_eulerian(k).unwrap()).sum();
+            assert_eq!(n.checked_factorial(), Some(eulerian_sum));
+            for k in 0..=n as u32 {
+                assert_eq!(
+                    n.checked_eulerian(k),
+                    eulerian_big(n, k as u64).and_then(|x| x.try_into().ok())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_triangles() {
+        assert_eq!(
+            stirling_first_triangle(5),
+            vec![1, 0, 1, 0, 1, 1, 0, 2, 3, 1, 0, 6, 11, 6, 1]
+        );
+        assert_eq!(
+            stirling_second_triangle(5),
+            vec![1, 0, 1, 0, 1, 1, 0, 1, 3, 1, 0, 1, 7, 6, 1]
+        );
+        assert_eq!(
+            bell_triangle(5),
+            vec![1, 1, 2, 2, 3, 5, 5, 7, 10, 15, 15, 20, 27, 37, 52]
+        );
+        assert_eq!(
+            lah_triangle(5),
+            vec![1, 0, 1, 0, 2, 1, 0, 6, 6, 1, 0, 24, 36, 12, 1]
+        );
+        assert_eq!(
+            eulerian_triangle(5),
+            vec![1, 1, 0, 1, 1, 0, 1, 4, 1, 0, 1, 11, 11, 1, 0]
+        );
+        assert_eq!(stirling_second_triangle(0), vec![]);
+    }
+
+    #[test]
+    fn test_partitions() {
+        assert_eq!(100_u32.checked_partitions(), Some(190_569_292));
+        assert_eq!(100_u64.checked_partitions(), Some(190_569_292));
+        assert_eq!(100_u128.checked_partitions(), Some(190_569_292));
+        assert_eq!(100_usize.checked_partitions(), Some(190_569_292));
+        assert_eq!(partitions_big(100), 190_569_292.to_biguint());
+        assert_eq!(
+            (0_u32..10)
+                .map(|n| n.checked_partitions().unwrap())
+                .collect::<Vec<_>>(),
+            vec![1, 1, 2, 3, 5, 7, 11, 15, 22, 30]
+        );
+        assert_eq!(127_u32.checked_partitions(), Some(3_913_864_295));
+        assert_eq!(128_u32.checked_partitions(), None);
+        assert_eq!(
+            1458_u128.checked_partitions().and_then(|x| x.to_biguint()),
+            partitions_big(1458)
+        );
+        assert_eq!(1459_u128.checked_partitions(), None);
+        for n in 0_u32..50 {
+            assert_eq!(n.checked_partitions(), n.checked_partitions_at_most(n));
+            assert_eq!(
+                n.checked_partitions().and_then(|x| x.to_biguint()),
+                partitions_at_most_big(n, n)
+            );
+        }
+    }
+
+    #[test]
+    fn test_distinct_partitions() {
+        assert_eq!(100_u32.checked_distinct_partitions(), Some(444_793));
+        assert_eq!(100_u64.checked_distinct_partitions(), Some(444_793));
+        assert_eq!(100_u128.checked_distinct_partitions(), Some(444_793));
+        assert_eq!(100_usize.checked_distinct_partitions(), Some(444_793));
+        assert_eq!(distinct_partitions_big(100), 444_793.to_biguint());
+        for n in 0_usize..30 {
+            let distinct = Partitions::new(n)
+                .filter(|partition| partition.windows(2).all(|w| w[0] > w[1]))
+                .count();
+            assert_eq!(n.checked_distinct_partitions(), Some(distinct));
+        }
+        assert_eq!(238_u32.checked_distinct_partitions(), None);
+    }
+
+    #[test]
+    fn test_partitions_at_most() {
+        assert_eq!(10_u32.checked_partitions_at_most(3), Some(14));
+        assert_eq!(10_u64.checked_partitions_at_most(3), Some(14));
+        assert_eq!(10_u128.checked_partitions_at_most(3), Some(14));
+        assert_eq!(10_usize.checked_partitions_at_most(3), Some(14));
+        assert_eq!(partitions_at_most_big(10, 3), 14.to_biguint());
+        assert_eq!(0_u32.checked_partitions_at_most(0), Some(1));
+        assert_eq!(5_u32.checked_partitions_at_most(0), Some(0));
+        for n in 0_usize..20 {
+            for k in 0..=n as u32 {
+                let at_most = Partitions::new(n)
+                    .filter(|partition| partition.len() <= k as usize)
+                    .count();
+                assert_eq!(n.checked_partitions_at_most(k), Some(at_most));
+            }
+        }
+    }
+
+    #[test]
+    fn test_partitions_iterator() {
+        assert_eq!(
+            Partitions::new(5).collect::<Vec<_>>(),
+            vec![
+                vec![1, 1, 1, 1, 1],
+                vec![2, 1, 1, 1],
+                vec![2, 2, 1],
+                vec![3, 1, 1],
+                vec![3, 2],
+                vec![4, 1],
+                vec![5]
+            ]
+        );
+        assert_eq!(Partitions::new(0).collect::<Vec<_>>(), vec![vec![]]);
+        for n in 0_usize..30 {
+            let partitions = Partitions::new(n).collect::<Vec<_>>();
+            assert_eq!(Some(partitions.len()), n.checked_partitions());
+            assert!(partitions.windows(2).all(|w| w[0] < w[1]));
+            assert!(partitions.iter().all(|p| p.iter().sum::<usize>() == n));
+        }
+    }
+
+    #[test]
+    fn test_permutations_iterator() {
+        let pool = ['a', 'b', 'c', 'd', 'e'];
+        for k in 0..=pool.len() {
+            let permutations = Permutations::new(&pool, k).collect::<Vec<_>>();
+            assert_eq!(permutations.len(), pool.len().permutation(k as u32));
+            assert!(permutations.windows(2).all(|w| w[0] < w[1]));
+        }
+        assert_eq!(Permutations::new(&pool, 6).count(), 0);
+        assert_eq!(
+            Permutations::<u32>::new(&[], 0).collect::<Vec<_>>(),
+            vec![vec![]]
+        );
+    }
+
+    #[test]
+    fn test_next_permutation() {
+        let mut v = [1, 2, 2, 3];
+        let mut permutations = vec![v];
+        while next_permutation(&mut v) {
+            permutations.push(v);
+        }
+        assert_eq!(permutations.len(), 12);
+        assert!(permutations.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(v, [1, 2, 2, 3]);
+        let mut v = [3, 2, 2, 1];
+        let mut count = 1;
+        while prev_permutation(&mut v) {
+            count += 1;
+        }
+        assert_eq!(count, 12);
+        assert_eq!(v, [3, 2, 2, 1]);
+        let mut v: [u32; 0] = [];
+        assert!(!next_permutation(&mut v));
+        assert!(!prev_permutation(&mut v));
+    }
+
+    #[test]
+    fn test_combinations_iterator() {
+        let pool = [1, 2, 3, 4, 5, 6];
+        for k in 0..=pool.len() {
+            let combinations = Combinations::new(&pool, k).collect::<Vec<_>>();
+            assert_eq!(combinations.len(), pool.len().combination(k as u32));
+            assert!(combinations.windows(2).all(|w| w[0] < w[1]));
+            assert!(combinations
+                .iter()
+                .all(|c| c.windows(2).all(|w| w[0] < w[1])));
+        }
+        assert_eq!(Combinations::new(&pool, 7).count(), 0);
+    }
+
+    #[test]
+    fn test_combinations_with_repetition_iterator() {
+        let pool = [1, 2, 3, 4];
+        for k in 0..6 {
+            let combinations = CombinationsWithRepetition::new(&pool, k).collect::<Vec<_>>();
+            assert_eq!(
+                combinations.len(),
+                (pool.len() + k - 1).combination(k as u32)
+            );
+            assert!(combinations.windows(2).all(|w| w[0] < w[1]));
+            assert!(combinations
+                .iter()
+                .all(|c| c.windows(2).all(|w| w[0] <= w[1])));
+        }
+        assert_eq!(CombinationsWithRepetition::<u32>::new(&[], 1).count(), 0);
+        assert_eq!(CombinationsWithRepetition::<u32>::new(&[], 0).count(), 1);
+    }
+
+    #[test]
+    fn test_rank_combination() {
+        for n in 0_usize..8 {
+            for k in 0..=n {
+                let pool = (0..n).collect::<Vec<_>>();
+                for (rank, combination) in Combinations::new(&pool, k).enumerate() {
+                    assert_eq!(rank_combination(&combination, n), Some(rank));
+                    assert_eq!(unrank_combination(rank, n, k), Some(combination.clone()));
+                    assert_eq!(rank_combination_big(&combination, n), rank.to_biguint());
+                    assert_eq!(unrank_combination_big(rank, n, k), Some(combination));
+                }
+                let count = n.combination(k as u32);
+                assert_eq!(unrank_combination(count, n, k), None);
+                assert_eq!(unrank_combination_big(count, n, k), None);
+            }
+        }
+        assert_eq!(rank_combination(&[1, 1], 4), None);
+        assert_eq!(rank_combination(&[1, 4], 4), None);
+        assert_eq!(rank_combination_big(&[2, 1], 4), None);
+        let combination = (1000..1500).step_by(5).collect::<Vec<_>>();
+        let rank = rank_combination_big(&combination, 2000).unwrap();
+        assert_eq!(unrank_combination_big(rank, 2000, 100), Some(combination));
+        // C(60, 30) fits in u64 although 60! / 30! does not.
+        let combination = (30..60).collect::<Vec<_>>();
+        let rank = rank_combination(&combination, 60);
+        assert_eq!(rank, Some(118_264_581_564_861_423));
+        assert_eq!(
+            rank.unwrap().to_biguint(),
+            rank_combination_big(&combination, 60)
+        );
+        assert_eq!(unrank_combination(rank.unwrap(), 60, 30), Some(combination));
+        assert_eq!(try_unrank_combination(0, 60, 30), Ok((0..30).collect()));
+        let combination = (0..60).step_by(2).collect::<Vec<_>>();
+        let rank = rank_combination(&combination, 60);
+        assert_eq!(
+            rank.unwrap().to_biguint(),
+            rank_combination_big(&combination, 60)
+        );
+        assert_eq!(
+            unrank_combination_big(rank.unwrap(), 60, 30),
+            Some(combination)
+        );
+    }
+
+    #[test]
+    fn test_rank_permutation() {
+        for n in 0_usize..7 {
+            let pool = (0..n).collect::<Vec<_>>();
+            for (rank, permutation) in Permutations::new(&pool, n).enumerate() {
+                assert_eq!(rank_permutation(&permutation), Some(rank));
+                assert_eq!(unrank_permutation(rank, n), Some(permutation.clone()));
+                assert_eq!(rank_permutation_big(&permutation), rank.to_biguint());
+                assert_eq!(unrank_permutation_big(rank, n), Some(permutation));
+            }
+            assert_eq!(unrank_permutation(n.factorial(), n), None);
+            assert_eq!(unrank_permutation_big(n.factorial(), n), None);
+        }
+        assert_eq!(rank_permutation(&[0, 2]), None);
+        assert_eq!(rank_permutation(&(0..21).collect::<Vec<_>>()), Some(0));
+        assert_eq!(rank_permutation(&(0..21).rev().collect::<Vec<_>>()), None);
+        let permutation = (0..500).map(|i| (i * 7) % 500).collect::<Vec<_>>();
+        let rank = rank_permutation_big(&permutation).unwrap();
+        assert_eq!(unrank_permutation_big(rank, 500), Some(permutation));
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(255_u32.digits_in(16), 2);
+        assert_eq!(255_u64.digits_in(16), 2);
+        assert_eq!(255_u128.digits_in(16), 2);
+        assert_eq!(255_usize.digits_in(16), 2);
+        assert_eq!(digits_in_big(255, 16), Some(2));
+        assert_eq!(0_u32.digits_in(2), 1);
+        assert_eq!(u64::MAX.digits_in(2), 64);
+        assert_eq!(u64::MAX.digits_in(10), u64::MAX.digits());
+        for base in 2_u64..=256 {
+            for n in [0, 1, base - 1, base, 1_000_000_007, u64::MAX] {
+                let digits = n.to_digits(base);
+                assert_eq!(digits.len() as u64, n.digits_in(base));
+                assert_eq!(u64::from_digits(&digits, base), Some(n));
+                assert_eq!(to_digits_big(n, base as u32), Some(digits.clone()));
+                assert_eq!(from_digits_big(&digits, base as u32), n.to_biguint());
+            }
+        }
+        assert_eq!(u32::from_digits(&[], 10), Some(0));
+        assert_eq!(u32::from_digits(&[4, 2, 9, 4, 9, 6, 7, 2, 9, 6], 10), None);
+        assert_eq!(u32::from_digits(&[10], 10), None);
+        assert_eq!(to_digits_big(10, 1), None);
+        assert_eq!(from_digits_big(&[10], 10), None);
+    }
+
+    #[test]
+    fn test_digit_sum() {
+        assert_eq!(1234_u32.digit_sum(10), 10);
+        assert_eq!(1234_u64.digit_sum(10), 10);
+        assert_eq!(1234_u128.digit_sum(10), 10);
+        assert_eq!(1234_usize.digit_sum(10), 10);
+        assert_eq!(digit_sum_big(1234, 10), 10.to_biguint());
+        assert_eq!(u64::MAX.digit_sum(2), 64);
+        assert_eq!(1234_u32.digital_root(10), 1);
+        assert_eq!(1234_u64.digital_root(10), 1);
+        assert_eq!(1234_u128.digital_root(10), 1);
+        assert_eq!(1234_usize.digital_root(10), 1);
+        assert_eq!(digital_root_big(1234, 10), 1.to_biguint());
+        for base in 2_u32..20 {
+            for n in 0_u32..1000 {
+                let mut root = n;
+                while root >= base {
+                    root = root.digit_sum(base);
+                }
+                assert_eq!(n.digital_root(base), root);
+                assert_eq!(digital_root_big(n, base), root.to_biguint());
+            }
+        }
+    }
+
+    #[test]
+    fn test_reverse_digits() {
+        assert_eq!(1234_u32.reverse_digits(10), 4321);
+        assert_eq!(1234_u64.reverse_digits(10), 4321);
+        assert_eq!(1234_u128.reverse_digits(10), 4321);
+        assert_eq!(1234_usize.reverse_digits(10), 4321);
+        assert_eq!(reverse_digits_big(1234, 10), 4321.to_biguint());
+        assert_eq!(1200_u32.reverse_digits(10), 21);
+        assert_eq!(0b1011_u32.reverse_digits(2), 0b1101);
+        assert_eq!(4_000_000_009_u32.checked_reverse_digits(10), None);
+        assert_eq!(
+            4_000_000_009_u64.checked_reverse_digits(10),
+            Some(9_000_000_004)
+        );
+        assert!(12321_u32.is_palindrome(10));
+        assert!(12321_u64.is_palindrome(10));
+        assert!(12321_u128.is_palindrome(10));
+        assert!(12321_usize.is_palindrome(10));
+        assert_eq!(is_palindrome_big(12321, 10), Some(true));
+        assert!(!1210_u32.is_palindrome(10));
+        assert!(0b10101_u32.is_palindrome(2));
+        assert!(0_u32.is_palindrome(10));
+        for base in 2_u32..20 {
+            for n in 0_u32..1000 {
+                assert_eq!(is_palindrome_big(n, base), Some(n.is_palindrome(base)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_error() {
+        assert_eq!(6_u32.try_factorial(), Ok(720));
+        assert_eq!(6_u64.try_factorial(), Ok(720));
+        assert_eq!(6_u128.try_factorial(), Ok(720));
+        assert_eq!(6_usize.try_factorial(), Ok(720));
+        assert_eq!(13_u32.try_factorial(), Err(Error::Overflow));
+        assert_eq!(
+            try_factorial_big(6),
+            720.to_biguint().ok_or(Error::Overflow)
+        );
+        assert_eq!(try_factorial_big(-6), Err(Error::NegativeInput));
+        for n in 0_u32..60 {
+            assert_eq!(n.try_fibonacci().ok(), n.checked_fibonacci());
+            assert_eq!(n.try_catalan().ok(), n.checked_catalan());
+            assert_eq!(n.try_combination(n / 2).ok(), n.checked_combination(n / 2));
+            assert_eq!(n.try_partitions().ok(), n.checked_partitions());
+        }
+        assert_eq!(10_u32.try_fibonacci_mod(0), Err(Error::InvalidModulus));
+        assert_eq!(try_fibonacci_mod_big(10, 0), Err(Error::InvalidModulus));
+        assert_eq!(try_fibonacci_mod_big(-10, 7), Err(Error::NegativeInput));
+        assert_eq!(0_u64.try_pisano_period(), Err(Error::InvalidModulus));
+        assert_eq!(10_u64.try_pisano_period(), Ok(60));
+        assert_eq!(4_000_000_000_u32.try_pisano_period(), Err(Error::Overflow));
+        assert_eq!(1234_u32.try_to_digits(257), Err(Error::InvalidBase));
+        assert_eq!(u32::try_from_digits(&[1, 2], 0), Err(Error::InvalidBase));
+        assert_eq!(u32::try_from_digits(&[1, 2], 2), Err(Error::InvalidInput));
+        assert_eq!(u32::try_from_digits(&[255], 256), Ok(255));
+        assert_eq!(u32::try_from_digits(&[1; 33], 2), Err(Error::Overflow));
+        assert_eq!(try_from_digits_big(&[1, 2], 2), Err(Error::InvalidInput));
+        assert_eq!(try_from_digits_big(&[1, 2], 1), Err(Error::InvalidBase));
+        assert_eq!(try_to_digits_big(-1, 10), Err(Error::NegativeInput));
+        assert_eq!(try_to_digits_big(1, 1), Err(Error::InvalidBase));
+        assert_eq!(1234_u32.try_reverse_digits(1), Err(Error::InvalidBase));
+        assert_eq!(
+            4_000_000_009_u32.try_reverse_digits(10),
+            Err(Error::Overflow)
+        );
+        assert_eq!(try_rank_combination(&[1, 1], 4), Err(Error::InvalidInput));
+        assert_eq!(try_unrank_combination(6, 4, 2), Err(Error::InvalidInput));
+        assert_eq!(try_rank_permutation(&[0, 2]), Err(Error::InvalidInput));
+        assert_eq!(try_unrank_permutation(6, 3), Err(Error::InvalidInput));
+        assert_eq!(try_rank_combination_big(&[4], 4), Err(Error::InvalidInput));
+        assert_eq!(try_unrank_permutation_big(-1, 3), Err(Error::NegativeInput));
+        let huge = u128::MAX;
+        assert_eq!(try_stirling_first_big(huge, 2), Err(Error::InvalidInput));
+        assert_eq!(try_bell_big(huge), Err(Error::InvalidInput));
+        assert_eq!(try_partitions_big(huge), Err(Error::InvalidInput));
+        assert_eq!(try_derangement_big(huge), Err(Error::InvalidInput));
+        assert_eq!(try_rencontres_big(huge, 0), Err(Error::InvalidInput));
+        assert_eq!(try_gcd_big(0, 0), Ok(0.to_biguint().unwrap()));
+        assert_eq!(try_lcm_big(4, 6), Ok(12.to_biguint().unwrap()));
+        assert_eq!(Error::Overflow.to_string(), "arithmetic overflow");
+    }
+
+    #[test]
+    fn test_unsigned_integer() {
+        use num_bigint::BigUint;
+        assert_eq!(5_u8.factorial(), 120);
+        assert_eq!(6_u8.checked_factorial(), None);
+        assert_eq!(8_u16.factorial(), 40_320);
+        assert_eq!(BigUint::from(6_u32).factorial(), BigUint::from(720_u32));
+        assert_eq!(BigUint::from(30_u32).checked_factorial(), factorial_big(30));
+        assert_eq!(
+            BigUint::from(150_u32).fibonacci(),
+            fibonacci_big(150).unwrap()
+        );
+        assert_eq!(
+            BigUint::from(30_u32).checked_combination(15),
+            combination_big(30, 15)
+        );
+        assert_eq!(
+            BigUint::from(1000_u32).checked_partitions(),
+            partitions_big(1000)
+        );
+        assert_eq!(
+            BigUint::from(30_u32).checked_stirling_second(3),
+            stirling_second_big(30, 3)
+        );
+        assert_eq!(
+            BigUint::from(1_000_000_007_u32).pisano_period(),
+            BigUint::from(2_000_000_016_u32)
+        );
+        assert_eq!(
+            BigUint::from(1247_u32).gcd(BigUint::from(1073_u32)),
+            BigUint::from(29_u32)
+        );
+        let n = BigUint::from(u128::MAX) * 3_u32;
+        assert_eq!(
+            n.to_digits(BigUint::from(256_u32)),
+            to_digits_big(n.clone(), 256).unwrap()
+        );
+        assert_eq!(
+            BigUint::from_digits(&n.to_digits(BigUint::from(7_u32)), BigUint::from(7_u32)),
+            Some(n)
+        );
+        for n in 0_u8..=255 {
+            assert_eq!(
+                n.checked_fibonacci(),
+                (n as u32)
+                    .checked_fibonacci()
+                    .and_then(|x| x.try_into().ok())
+            );
+            assert_eq!(
+                n.checked_bell(),
+                (n as u32).checked_bell().and_then(|x| x.try_into().ok())
+            );
+            assert_eq!(
+                n.checked_partitions(),
+                (n as u32)
+                    .checked_partitions()
+                    .and_then(|x| x.try_into().ok())
+            );
+            assert_eq!(
+                n.checked_catalan(),
+                (n as u32).checked_catalan().and_then(|x| x.try_into().ok())
+            );
+        }
+        // The Pisano period of a prime p = ±2 (mod 5) divides 2(p + 1), which may not fit.
+        assert_eq!(
+            4_294_967_087_u32.checked_pisano_period(),
+            Some(2_863_311_392)
+        );
+        for p in [
+            4_294_967_291_u32,
+            4_294_967_279,
+            4_294_967_197,
+            4_294_967_087,
+        ] {
+            assert_eq!(
+                p.checked_pisano_period(),
+                (p as u64)
+                    .checked_pisano_period()
+                    .and_then(|x| x.try_into().ok())
+            );
+        }
+    }
+
+    #[test]
+    fn test_bigint() {
+        assert_eq!(gcd_bigint(-1247, -1073), 29.to_bigint());
+        assert_eq!(gcd_bigint(0, 0), 0.to_bigint());
+        assert_eq!(lcm_bigint(-120, -50), 600.to_bigint());
+        for a in -30_i64..30 {
+            for b in -30_i64..30 {
+                let (g, x, y) = extended_gcd_bigint(a, b).unwrap();
+                assert_eq!(Some(g.clone()), gcd_bigint(a, b));
+                assert_eq!(a * x + b * y, g);
+            }
+        }
+        for n in 0_i64..20 {
+            for k in 0_i64..20 {
+                // C(-n, k) = (-1)^k C(n + k - 1, k).
+                let sign = if k % 2 == 0 { 1 } else { -1 };
+                assert_eq!(
+                    binomial_bigint(-n, k),
+                    binomial_bigint(n + k - 1, k).map(|x| x * sign)
+                );
+                assert_eq!(
+                    binomial_bigint(n, k),
+                    combination_big(n, k).and_then(|x| x.to_bigint())
+                );
+            }
+        }
+        assert_eq!(binomial_bigint(-1, 7), (-1).to_bigint());
+        assert_eq!(binomial_bigint(5, -1), 0.to_bigint());
+    }
+
+    #[test]
+    fn test_gcd_big() {
+        let mut state = 0x2545_f491_u32;
+        let mut random = |digits: usize| {
+            let digits = (0..digits).map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state
+            });
+            num_bigint::BigUint::new(digits.collect())
+        };
+        for digits in [1, 2, 3, 5, 40, 300, 1500] {
+            let g = random(digits / 3 + 1);
+            let (a, b) = (&g * random(digits), &g * random(digits / 2 + 1));
+            assert_eq!(
+                gcd_big(a.clone(), b.clone()),
+                Some(num_integer::Integer::gcd(&a, &b))
+            );
+            assert_eq!(
+                gcd_big(b.clone(), a.clone()),
+                Some(num_integer::Integer::gcd(&a, &b))
+            );
+        }
+        // Consecutive Fibonacci numbers take the most Euclid steps.
+        let fibonacci = |n: u32| fibonacci_big(n).unwrap();
+        assert_eq!(gcd_big(fibonacci(40000), fibonacci(40001)), 1.to_biguint());
+        assert_eq!(
+            gcd_big(fibonacci(30000), fibonacci(24000)),
+            fibonacci_big(6000)
+        );
+        // Operands well above HALF_GCD_THRESHOLD bits go through the half-gcd recursion.
+        let large = [
+            (random(4_000), random(3_000)),
+            (random(6_000), random(6_000)),
+            (fibonacci(150_000), fibonacci(150_001)),
+            (fibonacci(180_000), fibonacci(120_000)),
+            (random(3_000) * random(2_000), random(3_000) * random(500)),
+        ];
+        let shared = random(3_500);
+        let large = large.into_iter().chain([
+            (&shared * random(1_000), &shared * random(1_500)),
+            (&shared * &shared, &shared * random(2)),
+        ]);
+        for (a, b) in large {
+            assert!(a.bits().min(b.bits()) > 1 << 16);
+            let gcd = num_integer::Integer::gcd(&a, &b);
+            assert_eq!(gcd_big(a.clone(), b.clone()), Some(gcd.clone()));
+            assert_eq!(gcd_big(b, a), Some(gcd));
+        }
+        assert_eq!(
+            gcd_big(fibonacci(180_000), fibonacci(120_000)),
+            fibonacci_big(60_000)
+        );
+        assert_eq!(gcd_big(0, 7), 7.to_biguint());
+        assert_eq!(gcd_big(0, 0), 0.to_biguint());
+        assert_eq!(gcd_big(-4, 6), None);
+        assert_eq!(gcd_all_big([12, 18, 8]), 2.to_biguint());
+        assert_eq!(gcd_all_big([3, 5, -1]), 1.to_biguint());
+        assert_eq!(gcd_all_big([3, 6, -1]), None);
+        assert_eq!(lcm_all_big(Vec::<u32>::new()), 1.to_biguint());
+        assert_eq!(lcm_all_big([6, 10, 15]), 30.to_biguint());
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        use num_traits::ToPrimitive;
+        // Natural logarithm of a BigUint from its leading 64 bits.
+        let ln = |n: num_bigint::BigUint| {
+            let shift = n.bits().saturating_sub(64);
+            (n >> shift).to_f64().unwrap().ln() + shift as f64 * std::f64::consts::LN_2
+        };
+        for n in (0..=3000).step_by(7) {
+            let exact = ln(factorial_big(n).unwrap());
+            assert!((ln_factorial(n) - exact).abs() <= 1e-13 * exact.max(1.0));
+            assert!((ln_gamma(n as f64 + 1.0) - exact).abs() <= 1e-13 * exact.max(1.0));
+            for k in [0, n / 3, n / 2, n] {
+                let exact = ln(combination_big(n, k).unwrap());
+                assert!((ln_binomial(n, k) - exact).abs() <= 1e-11 * exact.max(1.0));
+            }
+        }
+        // Large n, where a difference of ln_factorial would cancel catastrophically.
+        for n in [1_000_000_000_000, 1_000_000_000_000_000, u64::MAX] {
+            for k in [1, 2, 14, 15, 16, 100, 1000, 2000] {
+                let exact = ln(combination_big(n, k).unwrap());
+                assert!((ln_binomial(n, k) - exact).abs() <= 1e-14 * exact);
+                assert_eq!(ln_binomial(n, n - k), ln_binomial(n, k));
+            }
+        }
+        assert!((ln_binomial(1_000_000_000_000_000, 1) - 34.538_776_394_910_684).abs() < 1e-14);
+        assert_eq!(ln_binomial(2, 3), f64::NEG_INFINITY);
+        // Γ(1/2 - n) = (-4)^n n! / (2n)! √π.
+        for n in 0_u32..20 {
+            let exact = ln(factorial_big(n).unwrap()) - ln(factorial_big(2 * n).unwrap())
+                + (4.0_f64.powi(n as i32) * std::f64::consts::PI.sqrt()).ln();
+            assert!((ln_gamma(0.5 - n as f64) - exact).abs() < 1e-12);
+        }
+        assert_eq!(ln_gamma(0.0), f64::INFINITY);
+        assert_eq!(ln_gamma(-7.0), f64::INFINITY);
+        assert!(ln_gamma(f64::NAN).is_nan());
+        assert_eq!(ln_gamma(f64::INFINITY), f64::INFINITY);
+        assert!(ln_factorial(u64::MAX).is_finite());
+        for x in [3.0, 10.0, 40.0] {
+            let exact = ln(factorial_big(x as u32 - 1).unwrap());
+            for terms in 0..10 {
+                let (approximation, bound) = ln_gamma_stirling(x, terms);
+                assert!((approximation - exact).abs() <= bound + 1e-13 * exact);
+            }
+        }
+        assert!(ln_gamma_stirling(-1.0, 3).0.is_nan());
+    }
+
+    #[test]
+    fn test_rational() {
+        let (a, b) = (Rational::new(-6, 4), Rational::new(5, -10));
+        assert_eq!((a.numer(), a.denom()), (&(-3).into(), &2.into()));
+        assert_eq!(&a + &b, Rational::from(-2));
+        assert_eq!(&a - &b, Rational::from(-1));
+        assert_eq!(&a * &b, Rational::new(3, 4));
+        assert_eq!(&a / &b, Rational::from(3));
+        assert_eq!(-&a, a.abs());
+        assert!(a < b && b < Rational::new(0, 7));
+        assert_eq!(Rational::new(0, -3).to_string(), "0");
+        assert_eq!(Rational::new(1, 3).to_f64(), 1.0 / 3.0);
+        assert_eq!(Rational::new(-1, 3).to_f64(), -1.0 / 3.0);
+        assert_eq!(
+            Rational::new(num_bigint::BigInt::from(1) << 1100, 3).to_f64(),
+            f64::INFINITY
+        );
+        // Exact value of a finite f64.
+        let exact = |x: f64| {
+            let (bits, one) = (x.to_bits(), num_bigint::BigInt::from(1));
+            let exp = ((bits >> 52) & 0x7ff) as i64;
+            let mantissa = match exp {
+                0 => bits & ((1 << 52) - 1),
+                _ => bits & ((1 << 52) - 1) | 1 << 52,
+            };
+            let exp = exp.max(1) - 1075;
+            let value = Rational::new(
+                num_bigint::BigInt::from(mantissa) << exp.max(0) as u32,
+                &one << (-exp).max(0) as u32,
+            );
+            if x < 0.0 {
+                -value
+            } else {
+                value
+            }
+        };
+        let tiny = Rational::new(1, num_bigint::BigInt::from(1) << 1200);
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        for i in 0..2000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            // Alternate normal numbers of any magnitude with subnormal numbers.
+            let bits = match i % 2 {
+                0 => state % 0x7fe0_0000_0000_0000,
+                _ => state % (1 << 52),
+            };
+            let (x, next) = (f64::from_bits(bits), f64::from_bits(bits + 1));
+            let midpoint = (exact(x) + exact(next)) / Rational::from(2);
+            let even = if bits % 2 == 0 { x } else { next };
+            assert_eq!(exact(x).to_f64(), x);
+            assert_eq!((-exact(x)).to_f64(), -x);
+            assert_eq!(midpoint.to_f64(), even);
+            assert_eq!((&midpoint - &tiny).to_f64(), x);
+            assert_eq!((&midpoint + &tiny).to_f64(), next);
+        }
+        assert_eq!(Rational::new(1, 10).to_f64(), 0.1);
+        assert_eq!(Rational::new(2, 3).to_f64(), 2.0 / 3.0);
+        // Halfway between f64::MAX and 2^1024 rounds to the even 2^1024, which overflows.
+        let limit = (exact(f64::MAX) + Rational::from(num_bigint::BigInt::from(1) << 1024))
+            / Rational::from(2);
+        assert_eq!(limit.to_f64(), f64::INFINITY);
+        assert_eq!((&limit - &tiny).to_f64(), f64::MAX);
+        assert_eq!((exact(f64::from_bits(1)) / Rational::from(2)).to_f64(), 0.0);
+        assert_eq!(
+            (exact(f64::from_bits(1)) * Rational::new(3, 2)).to_f64(),
+            f64::from_bits(2)
+        );
+        assert_eq!(Rational::try_new(0, 0), Err(Error::InvalidInput));
+
+        for n in 0_u32..30 {
+            let sum: Rational = (1..=n).map(|k| Rational::new(1, k)).sum();
+            assert_eq!(harmonic_big(n), Some(sum));
+        }
+        assert_eq!(
+            (0_u32..=10)
+                .map(|n| bernoulli_big(n).unwrap())
+                .collect::<Vec<_>>(),
+            [
+                (1, 1),
+                (-1, 2),
+                (1, 6),
+                (0, 1),
+                (-1, 30),
+                (0, 1),
+                (1, 42),
+                (0, 1),
+                (-1, 30)
+            ]
+            .into_iter()
+            .chain([(0, 1), (5, 66)])
+            .map(|(p, q)| Rational::new(p, q))
+            .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            bernoulli_big(30),
+            Some(Rational::new(8_615_841_276_005_i64, 14_322))
+        );
+        for p in 0_u32..12 {
+            let coefficients = faulhaber_big(p).unwrap();
+            for n in 0_u32..15 {
+                let value: Rational = coefficients
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| c * &Rational::from(n.to_biguint().unwrap().pow(i as u32)))
+                    .sum();
+                let sum: num_bigint::BigUint =
+                    (1..=n).map(|k| k.to_biguint().unwrap().pow(p)).sum();
+                assert_eq!(value, Rational::from(sum));
+            }
+        }
+    }
+
+    #[test]
+    fn test_continued_fraction() {
+        for p in 0_u64..60 {
+            for q in 1_u64..60 {
+                let terms = continued_fraction(p, q);
+                let (x, y) = Convergents::new(terms.clone()).last().unwrap();
+                assert_eq!((x, y), (p / p.gcd(q), q / p.gcd(q)));
+                for max in 1..=q {
+                    // The closest fraction with denominator at most max, preferring the smaller
+                    // denominator on ties.
+                    let (mut best_x, mut best_y) = (0, 1);
+                    for b in 1..=max {
+                        for a in [p * b / q, p * b / q + 1] {
+                            let error = |x: u64, y: u64| (x * q).abs_diff(p * y) as u128;
+                            if error(a, b) * (best_y as u128) < error(best_x, best_y) * (b as u128)
+                            {
+                                (best_x, best_y) = (a, b);
+                            }
+                        }
+                    }
+                    assert_eq!(
+                        best_approximation(terms.clone(), max),
+                        Some((best_x, best_y)),
+                        "{p}/{q} {max}"
+                    );
+                }
+            }
+        }
+        assert_eq!(best_approximation([3_u32, 7], 0), None);
+        for n in 0_u32..200 {
+            let (a0, period) = sqrt_continued_fraction(n);
+            let terms = std::iter::once(a0).chain(period.iter().copied().cycle());
+            for (k, (x, y)) in Convergents::new(terms.map(u64::from)).take(6).enumerate() {
+                // Convergents of sqrt(n) alternate around it, starting from below.
+                let ordering = (x * x).cmp(&(u64::from(n) * y * y));
+                if period.is_empty() {
+                    assert_eq!(ordering, std::cmp::Ordering::Equal);
+                } else if k % 2 == 0 {
+                    assert_eq!(ordering, std::cmp::Ordering::Less);
+                } else {
+                    assert_eq!(ordering, std::cmp::Ordering::Greater);
+                }
+            }
+            match pell_big(n) {
+                Some((x, y)) => assert_eq!(&x * &x - n * &y * &y, 1.to_biguint().unwrap()),
+                None => assert!(period.is_empty()),
+            }
+        }
+        assert_eq!(
+            Convergents::new([u64::MAX, 1, 1]).collect::<Vec<_>>(),
+            vec![(u64::MAX, 1)]
+        );
+        assert_eq!(
+            pell_big(661).map(|(x, _)| x.to_string()),
+            Some("16421658242965910275055840472270471049".to_string())
+        );
+    }
+
+    #[test]
+    fn test_diophantine() {
+        for a in -12_i64..=12 {
+            for b in -12_i64..=12 {
+                for c in -30_i64..=30 {
+                    let brute: Vec<(i64, i64)> = (0..=20)
+                        .flat_map(|x| (0..=15).map(move |y| (x, y)))
+                        .filter(|(x, y)| a * x + b * y == c)
+                        .collect();
+                    let Some(solutions) = solve_linear_diophantine(a, b, c) else {
+                        assert!(brute.is_empty() || (a == 0 && b == 0));
+                        continue;
+                    };
+                    for k in -3..=3 {
+                        let (x, y) = solutions.solution(&k).unwrap();
+                        assert_eq!(a * x + b * y, c);
+                    }
+                    assert!(b == 0 || (0..solutions.dx).contains(&solutions.x0));
+                    assert_eq!(
+                        solutions.non_negative_solutions(20, 15).collect::<Vec<_>>(),
+                        brute
+                    );
+                }
+            }
+        }
+        assert_eq!(solve_linear_diophantine(0_i64, 0, 0), None);
+        assert_eq!(
+            solve_linear_diophantine(i64::MAX, i64::MAX - 1, 1)
+                .unwrap()
+                .dx,
+            i64::MAX - 1
+        );
+
+        let coin_sets: [&[u32]; 6] = [
+            &[3, 5],
+            &[6, 9, 20],
+            &[7, 11, 13],
+            &[4, 6, 9, 15],
+            &[10, 15, 21],
+            &[12, 13, 14, 15],
+        ];
+        for coins in coin_sets {
+            let mut payable = vec![true; 1000];
+            for n in 1..1000 {
+                payable[n] = coins
+                    .iter()
+                    .any(|&c| c as usize <= n && payable[n - c as usize]);
+            }
+            let expected = (0..1000).rev().find(|&n| !payable[n]).unwrap() as u32;
+            assert_eq!(frobenius_number(coins), Some(expected));
+        }
+        assert_eq!(frobenius_number(&[0_u32, 5, 3, 5]), Some(7));
+        assert_eq!(frobenius_number(&[1_u32, 5]), None);
+        assert_eq!(frobenius_number(&[6_u32, 10, 15, 0]), Some(29));
+        assert_eq!(frobenius_number::<u32>(&[]), None);
+        assert_eq!(frobenius_number(&[u64::MAX, u64::MAX - 1]), None);
     }
 
     #[test]
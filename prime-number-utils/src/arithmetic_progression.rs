@@ -0,0 +1,123 @@
+use crate::{baillie_psw, mod_inverse, BitwiseSieve, GenPrime};
+use number_utils::NumberUtils;
+
+/// Implementation of a segmented sieve over the residue class `a (mod m)`, which only sieves the
+/// numbers `a + km` and so takes time proportional to `1 / m` of the range.
+///
+/// # Panics
+/// Panics if the modulus is zero.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::{ArithmeticProgressionSieve, GenPrime};
+/// # fn main() {
+/// let mut sieve = ArithmeticProgressionSieve::new(7, 10);
+/// assert_eq!(sieve.gen_range(100..200), vec![107, 127, 137, 157, 167, 197]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ArithmeticProgressionSieve {
+    residue: usize,
+    modulus: usize,
+    min: usize,
+    max: usize,
+}
+
+impl ArithmeticProgressionSieve {
+    /// Creates a new sieve for the primes congruent to `residue` modulo `modulus`.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    pub fn new(residue: usize, modulus: usize) -> Self {
+        assert!(modulus != 0, "modulus must not be zero");
+        Self {
+            residue: residue % modulus,
+            modulus,
+            min: 0,
+            max: 0,
+        }
+    }
+}
+
+impl GenPrime for ArithmeticProgressionSieve {
+    fn gen_range(&mut self, range: std::ops::Range<usize>) -> Vec<usize> {
+        self.min = range.start;
+        self.max = range.end;
+        self.gen()
+    }
+
+    fn gen(&mut self) -> Vec<usize> {
+        let (r, m) = (self.residue, self.modulus);
+        if self.max < 2 {
+            return vec![];
+        }
+        if r.gcd(m) != 1 {
+            // Every term is divisible by gcd(r, m), so only the least positive term can be prime.
+            let n = if r == 0 { m } else { r };
+            return if (self.min..self.max).contains(&n) && baillie_psw(n) {
+                vec![n]
+            } else {
+                vec![]
+            };
+        }
+        // The terms r + km in the range have k in low..high.
+        let (low, high) = (
+            self.min.saturating_sub(r).div_ceil(m),
+            self.max.saturating_sub(r).div_ceil(m),
+        );
+        let n = (self.max as f64).sqrt() as usize;
+        let segment = n.max(1);
+        // The k with q | r + km form the residue class -r / m (mod q) for primes q not dividing m.
+        let primes: Vec<(usize, usize)> = BitwiseSieve::new()
+            .gen_range(0..n + 1)
+            .into_iter()
+            .filter(|q| m % q != 0)
+            .map(|q| {
+                let inverse = mod_inverse((m % q) as u64, q as u64).expect("q does not divide m");
+                (q, ((q - r % q) % q * inverse as usize) % q)
+            })
+            .collect();
+        let mut result = vec![];
+        let mut start = low;
+        while start < high {
+            let end = high.min(start + segment);
+            let mut sieve = vec![0; end - start];
+            for &(q, k0) in &primes {
+                let first = start + (k0 + q - start % q) % q;
+                for k in (first..end).step_by(q) {
+                    if r + k * m != q {
+                        sieve[k - start] = 1;
+                    }
+                }
+            }
+            for k in start..end {
+                let p = r + k * m;
+                if sieve[k - start] == 0 && p >= 2 {
+                    result.push(p);
+                }
+            }
+            start = end;
+        }
+        result
+    }
+}
+
+/// Counts the primes `p <= x` with `p = a (mod m)` using `ArithmeticProgressionSieve`.
+///
+/// # Panics
+/// Panics if `m` is zero.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::prime_pi_mod;
+/// # fn main() {
+/// assert_eq!(prime_pi_mod(1_000_000, 1, 4), 39_175);
+/// assert_eq!(prime_pi_mod(1_000_000, 3, 4), 39_322);
+/// assert_eq!(prime_pi_mod(1_000_000, 2, 4), 1);
+/// # }
+/// ```
+pub fn prime_pi_mod(x: usize, a: usize, m: usize) -> usize {
+    ArithmeticProgressionSieve::new(a, m)
+        .gen_range(0..x.saturating_add(1))
+        .len()
+}
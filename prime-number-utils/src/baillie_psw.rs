@@ -1,10 +1,11 @@
 use std::mem::swap;
 
-struct MontgomeryMultiplication<BitCountType = u32> {
-    n: usize,
+/// Montgomery modular multiplication modulo an odd `n`, with `r` as the Montgomery form of one.
+pub(crate) struct MontgomeryMultiplication<BitCountType = u32> {
+    pub(crate) n: usize,
     ni: usize,
     nh: usize,
-    r: usize,
+    pub(crate) r: usize,
     rn: usize,
     r2: usize,
     d: usize,
@@ -12,7 +13,7 @@ struct MontgomeryMultiplication<BitCountType = u32> {
 }
 
 impl MontgomeryMultiplication {
-    fn new(n: usize) -> Self {
+    pub(crate) fn new(n: usize) -> Self {
         let mut ni = n;
         for _ in 0..5 {
             ni = ni.wrapping_mul(2_usize.wrapping_sub(n.wrapping_mul(ni)));
@@ -36,7 +37,7 @@ impl MontgomeryMultiplication {
         }
     }
 
-    fn add(&self, a: usize, b: usize) -> usize {
+    pub(crate) fn add(&self, a: usize, b: usize) -> usize {
         let (t, fa) = a.overflowing_add(b);
         let (u, fs) = t.overflowing_sub(self.n);
         if fa {
@@ -51,7 +52,7 @@ impl MontgomeryMultiplication {
         }
     }
 
-    fn sub(&self, a: usize, b: usize) -> usize {
+    pub(crate) fn sub(&self, a: usize, b: usize) -> usize {
         let (t, f) = a.overflowing_sub(b);
         if f {
             t.wrapping_add(self.n)
@@ -68,7 +69,7 @@ impl MontgomeryMultiplication {
         }
     }
 
-    fn mrmul(&self, ar: usize, br: usize) -> usize {
+    pub(crate) fn mrmul(&self, ar: usize, br: usize) -> usize {
         let (n, ni) = (self.n, self.ni);
         let t: u128 = (ar as u128) * (br as u128);
         let (t, f) = ((t >> 64) as usize).overflowing_sub(
@@ -81,8 +82,7 @@ impl MontgomeryMultiplication {
         }
     }
 
-    #[allow(dead_code)]
-    fn mr(&self, ar: usize) -> usize {
+    pub(crate) fn mr(&self, ar: usize) -> usize {
         let (n, ni) = (self.n, self.ni);
         let (t, f) =
             (((((ar.wrapping_mul(ni)) as u128) * (n as u128)) >> 64) as usize).overflowing_neg();
@@ -93,11 +93,11 @@ impl MontgomeryMultiplication {
         }
     }
 
-    fn ar(&self, a: usize) -> usize {
+    pub(crate) fn ar(&self, a: usize) -> usize {
         self.mrmul(a, self.r2)
     }
 
-    fn powir(&self, mut ar: usize, mut b: usize) -> usize {
+    pub(crate) fn powir(&self, mut ar: usize, mut b: usize) -> usize {
         let mut t = if (b & 1) == 0 { self.r } else { ar };
         b >>= 1;
         while b != 0 {
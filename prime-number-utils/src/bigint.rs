@@ -1,6 +1,92 @@
-use crate::baillie_psw;
-use num_bigint::ToBigUint;
+use crate::{baillie_psw, BitwiseSieve, Error, GenPrime};
+use num_bigint::{BigUint, ToBigUint};
+use num_integer::Integer;
 use num_iter::range;
+use num_traits::{One, ToPrimitive, Zero};
+use rand_core::RngCore;
+use std::{mem::swap, sync::OnceLock};
+
+/// Primes below this bound are used by trial division to reject candidates before the probable
+/// prime tests of `baillie_psw_big` are applied.
+const PRE_FILTER_LIMIT: usize = 1 << 12;
+
+/// A modulus `k 2^n + 1` or `k 2^n - 1` with small `k`. Since `k 2^n = -1` or `1` modulo it, the
+/// high bits of a number can be folded into the low bits with shifts and a division by `k`, which
+/// is much faster than a division by the modulus.
+struct SpecialForm {
+    k: usize,
+    n: usize,
+    plus_one: bool,
+    modulus: BigUint,
+    mask: BigUint,
+}
+
+impl SpecialForm {
+    fn new(k: usize, n: usize, plus_one: bool) -> Self {
+        let power = BigUint::one() << n;
+        let modulus = if plus_one {
+            BigUint::from(k) * &power + 1_u32
+        } else {
+            BigUint::from(k) * &power - 1_u32
+        };
+        Self {
+            k,
+            n,
+            plus_one,
+            modulus,
+            mask: power - 1_u32,
+        }
+    }
+
+    /// Calculates `x mod self.modulus`.
+    fn reduce(&self, mut x: BigUint) -> BigUint {
+        // Writing x = (ak + b) 2^n + low with b < k, x = b 2^n + low - a modulo k 2^n + 1 and
+        // x = b 2^n + low + a modulo k 2^n - 1.
+        loop {
+            let high = &x >> self.n;
+            if high.to_usize().is_some_and(|high| high <= self.k) {
+                break;
+            }
+            x &= &self.mask;
+            // No division is needed for k = 1, and a k that fits in a digit is divided digit by
+            // digit.
+            let (a, low) = if self.k == 1 {
+                (high, x)
+            } else if let Ok(k) = u32::try_from(self.k) {
+                let b = &high % k;
+                (high / k, (b << self.n) | x)
+            } else {
+                let (a, b) = high.div_rem(&BigUint::from(self.k));
+                (a, (b << self.n) | x)
+            };
+            x = if !self.plus_one {
+                low + a
+            } else if low >= a {
+                low - a
+            } else {
+                let multiple = (&a - &low).div_ceil(&self.modulus) * &self.modulus;
+                low + multiple - a
+            };
+        }
+        while x >= self.modulus {
+            x -= &self.modulus;
+        }
+        x
+    }
+
+    fn square(&self, x: &BigUint) -> BigUint {
+        self.reduce(x * x)
+    }
+
+    /// Calculates `x - 2 mod self.modulus` for `x < self.modulus`.
+    fn sub_two(&self, x: BigUint) -> BigUint {
+        if x < BigUint::from(2_u32) {
+            x + &self.modulus - 2_u32
+        } else {
+            x - 2_u32
+        }
+    }
+}
 
 /// Lucas-Lehmer test, a primality test for Mersenne numbers.
 ///
@@ -23,15 +109,475 @@ pub fn lucas_lehmer(n: usize) -> Option<bool> {
     if !baillie_psw(n) {
         return Some(false);
     }
+    u32::try_from(n).ok()?;
+    let m = SpecialForm::new(1, n, false);
     let mut s = 4.to_biguint()?;
-    let m = 2.to_biguint()?.pow(n as u32) - 1.to_biguint()?;
     for _ in range(2, n) {
-        let ss = s.pow(2);
-        s = (&ss & &m) + (ss >> n);
-        if s >= m {
-            s -= &m;
-        }
-        s -= 2.to_biguint()?;
+        s = m.sub_two(m.square(&s));
     }
     Some(s == 0.to_biguint()?)
 }
+
+/// Lucas-Lehmer test, returning an error if the exponent is too large.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::{try_lucas_lehmer, Error};
+/// # fn main() {
+/// assert_eq!(try_lucas_lehmer(13), Ok(true));
+/// assert_eq!(try_lucas_lehmer(11), Ok(false));
+/// # }
+/// ```
+pub fn try_lucas_lehmer(n: usize) -> Result<bool, Error> {
+    if u32::try_from(n).is_err() {
+        return Err(Error::Overflow);
+    }
+    lucas_lehmer(n).ok_or(Error::Overflow)
+}
+
+/// Proth's test, a primality test for Proth numbers `N = k 2^n + 1` with odd `k < 2^n`, which are
+/// prime if and only if `a^((N - 1) / 2) = -1 (mod N)` for a quadratic non-residue `a`. Returns
+/// None if `k 2^n + 1` is not a Proth number.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::proth_test;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(proth_test(3, 189)?, true);
+/// assert_eq!(proth_test(3, 190)?, false);
+/// assert_eq!(proth_test(3, 1), None);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn proth_test(k: usize, n: usize) -> Option<bool> {
+    if k.is_multiple_of(2) || n == 0 || k.checked_shr(u32::try_from(n).ok()?).unwrap_or(0) != 0 {
+        return None;
+    }
+    let m = SpecialForm::new(k, n, true);
+    if &m.modulus.sqrt() * &m.modulus.sqrt() == m.modulus {
+        return Some(false);
+    }
+    for a in 3_u32.. {
+        let a = BigUint::from(a);
+        match jacobi_symbol_big(&a, &m.modulus) {
+            0 => return Some(a == m.modulus),
+            1 => continue,
+            _ => {}
+        }
+        let mut x = a.modpow(&BigUint::from(k), &m.modulus);
+        for _ in 1..n {
+            x = m.square(&x);
+        }
+        return Some(x + 1_u32 == m.modulus);
+    }
+    unreachable!()
+}
+
+/// Proth's test, returning an error if `k 2^n + 1` is not a Proth number or the exponent is too
+/// large.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::{try_proth_test, Error};
+/// # fn main() {
+/// assert_eq!(try_proth_test(3, 189), Ok(true));
+/// assert_eq!(try_proth_test(4, 189), Err(Error::InvalidInput));
+/// assert_eq!(try_proth_test(3, 1 << 40), Err(Error::Overflow));
+/// # }
+/// ```
+pub fn try_proth_test(k: usize, n: usize) -> Result<bool, Error> {
+    if u32::try_from(n).is_err() {
+        return Err(Error::Overflow);
+    }
+    proth_test(k, n).ok_or(Error::InvalidInput)
+}
+
+/// Pépin's test, a primality test for Fermat numbers `2^(2^n) + 1`, which are prime if and only if
+/// `3^((F - 1) / 2) = -1 (mod F)`. Returns None if `2^n` overflows.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::pepin_test;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(pepin_test(4)?, true);
+/// assert_eq!(pepin_test(5)?, false);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn pepin_test(n: usize) -> Option<bool> {
+    if n == 0 {
+        return Some(true);
+    }
+    let m = SpecialForm::new(1, 1_usize.checked_shl(u32::try_from(n).ok()?)?, true);
+    let mut x = 3.to_biguint()?;
+    for _ in 1..m.n {
+        x = m.square(&x);
+    }
+    Some(x + 1_u32 == m.modulus)
+}
+
+/// Pépin's test, returning an error if `2^n` overflows.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::{try_pepin_test, Error};
+/// # fn main() {
+/// assert_eq!(try_pepin_test(3), Ok(true));
+/// assert_eq!(try_pepin_test(100), Err(Error::Overflow));
+/// # }
+/// ```
+pub fn try_pepin_test(n: usize) -> Result<bool, Error> {
+    pepin_test(n).ok_or(Error::Overflow)
+}
+
+/// Lucas-Lehmer-Riesel test, a primality test for `k 2^n - 1` with odd `k < 2^n`, which
+/// generalizes the Lucas-Lehmer test with the starting value `V_k(P, 1)` of Rödseth. Returns None
+/// if `k` is even or not less than `2^n`.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::lucas_lehmer_riesel;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(lucas_lehmer_riesel(3, 143)?, true);
+/// assert_eq!(lucas_lehmer_riesel(3, 144)?, false);
+/// assert_eq!(lucas_lehmer_riesel(1, 127)?, true);
+/// assert_eq!(lucas_lehmer_riesel(2, 127), None);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn lucas_lehmer_riesel(k: usize, n: usize) -> Option<bool> {
+    if k.is_multiple_of(2) || n < 2 || k.checked_shr(u32::try_from(n).ok()?).unwrap_or(0) != 0 {
+        return None;
+    }
+    let m = SpecialForm::new(k, n, false);
+    // Rödseth's criterion: P with ((P - 2) / N) = 1 and ((P + 2) / N) = -1.
+    let mut p = 3_u32;
+    loop {
+        let (minus, plus) = (BigUint::from(p - 2), BigUint::from(p + 2));
+        let symbols = (
+            jacobi_symbol_big(&minus, &m.modulus),
+            jacobi_symbol_big(&plus, &m.modulus),
+        );
+        if symbols.0 == 0 && !(&minus % &m.modulus).is_zero()
+            || symbols.1 == 0 && !(&plus % &m.modulus).is_zero()
+        {
+            return Some(false);
+        }
+        if symbols == (1, -1) {
+            break;
+        }
+        p += 1;
+    }
+    // V_k(P, 1) with V_2j = V_j^2 - 2 and V_2j+1 = V_j V_j+1 - P.
+    let p = BigUint::from(p);
+    let (mut v, mut w) = (2.to_biguint()?, p.clone());
+    for i in (0..usize::BITS - k.leading_zeros()).rev() {
+        let vw = (&v * &w + &m.modulus - &p) % &m.modulus;
+        if k >> i & 1 == 1 {
+            (v, w) = (vw, m.sub_two(m.square(&w)));
+        } else {
+            (v, w) = (m.sub_two(m.square(&v)), vw);
+        }
+    }
+    for _ in 2..n {
+        v = m.sub_two(m.square(&v));
+    }
+    Some(v.is_zero())
+}
+
+/// Lucas-Lehmer-Riesel test, returning an error if `k` is even or not less than `2^n`, or the
+/// exponent is too large.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::{try_lucas_lehmer_riesel, Error};
+/// # fn main() {
+/// assert_eq!(try_lucas_lehmer_riesel(3, 143), Ok(true));
+/// assert_eq!(try_lucas_lehmer_riesel(5, 2), Err(Error::InvalidInput));
+/// assert_eq!(try_lucas_lehmer_riesel(3, 1 << 40), Err(Error::Overflow));
+/// # }
+/// ```
+pub fn try_lucas_lehmer_riesel(k: usize, n: usize) -> Result<bool, Error> {
+    if u32::try_from(n).is_err() {
+        return Err(Error::Overflow);
+    }
+    lucas_lehmer_riesel(k, n).ok_or(Error::InvalidInput)
+}
+
+/// Calculates the lowest 32 bits.
+fn low_u32(n: &BigUint) -> u32 {
+    n.iter_u32_digits().next().unwrap_or(0)
+}
+
+/// Calculates the Jacobi symbol `(a / n)` for odd `n`.
+fn jacobi_symbol_big(a: &BigUint, n: &BigUint) -> i32 {
+    let (mut a, mut n) = (a % n, n.clone());
+    let mut j = 1;
+    while !a.is_zero() {
+        let z = a.trailing_zeros().unwrap_or(0);
+        a >>= z;
+        if z & 1 == 1 && matches!(low_u32(&n) & 7, 3 | 5) {
+            j = -j;
+        }
+        if low_u32(&a) & low_u32(&n) & 3 == 3 {
+            j = -j;
+        }
+        swap(&mut a, &mut n);
+        a %= &n;
+    }
+    if n.is_one() {
+        j
+    } else {
+        0
+    }
+}
+
+/// Calculates `d % n` for a possibly negative `d`.
+fn residue(d: i64, n: &BigUint) -> BigUint {
+    let r = BigUint::from(d.unsigned_abs()) % n;
+    if d < 0 && !r.is_zero() {
+        n - r
+    } else {
+        r
+    }
+}
+
+/// Strong probable prime test to base 2 for odd `n > 2`.
+fn miller_rabin_base_2_big(n: &BigUint) -> bool {
+    let n1 = n - 1_u32;
+    let s = n1.trailing_zeros().unwrap_or(0);
+    let mut x = BigUint::from(2_u32).modpow(&(&n1 >> s), n);
+    if x.is_one() || x == n1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = &x * &x % n;
+        if x == n1 {
+            return true;
+        }
+        if x.is_one() {
+            return false;
+        }
+    }
+    false
+}
+
+/// Strong Lucas probable prime test with Selfridge's parameters for odd `n` that is not a perfect
+/// square and has no prime factors below `PRE_FILTER_LIMIT`.
+fn strong_lucas_big(n: &BigUint) -> bool {
+    let mut d: i64 = 5;
+    loop {
+        match jacobi_symbol_big(&residue(d, n), n) {
+            -1 => break,
+            0 => return false,
+            _ => d = if d > 0 { -(d + 2) } else { 2 - d },
+        }
+    }
+    let (dm, qm) = (residue(d, n), residue((1 - d) / 4, n));
+    let half = |x: BigUint| {
+        let x = x % n;
+        if x.bit(0) {
+            (x + n) >> 1
+        } else {
+            x >> 1
+        }
+    };
+    let double_v = |v: &BigUint, qk: &BigUint| (v * v + (n << 1) - (qk << 1)) % n;
+    // n + 1 = k 2^s for odd k, and U(k), V(k) and Q^k are calculated from the top bit of k down.
+    let n1 = n + 1_u32;
+    let s = n1.trailing_zeros().unwrap_or(0);
+    let k = n1 >> s;
+    let (mut u, mut v, mut qk) = (BigUint::one(), BigUint::one(), qm.clone());
+    for i in (0..k.bits() - 1).rev() {
+        u = &u * &v % n;
+        v = double_v(&v, &qk);
+        qk = &qk * &qk % n;
+        if k.bit(i) {
+            (u, v) = (half(&u + &v), half(&dm * &u + &v));
+            qk = &qk * &qm % n;
+        }
+    }
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+    for _ in 1..s {
+        v = double_v(&v, &qk);
+        if v.is_zero() {
+            return true;
+        }
+        qk = &qk * &qk % n;
+    }
+    false
+}
+
+/// Implementation of Baillie-PSW for `BigUint`, combining trial division by small primes, a strong
+/// probable prime test to base 2 and a strong Lucas probable prime test.
+///
+/// # Examples
+/// ```
+/// # use num_bigint::BigUint;
+/// # use prime_number_utils::baillie_psw_big;
+/// # fn main() {
+/// let mersenne = (BigUint::from(1_u32) << 127) - 1_u32;
+/// assert!(baillie_psw_big(&mersenne));
+/// assert!(!baillie_psw_big(&(mersenne + 2_u32)));
+/// # }
+/// ```
+pub fn baillie_psw_big(n: &BigUint) -> bool {
+    if let Some(n) = n.to_usize() {
+        return baillie_psw(n);
+    }
+    !has_small_factor(n) && baillie_psw_big_unfiltered(n)
+}
+
+/// Baillie-PSW without the trial division, for `n` that is known to have no prime factors below
+/// `PRE_FILTER_LIMIT` unless it fits in `usize`.
+fn baillie_psw_big_unfiltered(n: &BigUint) -> bool {
+    if let Some(n) = n.to_usize() {
+        return baillie_psw(n);
+    }
+    miller_rabin_base_2_big(n) && &n.sqrt() * &n.sqrt() != *n && strong_lucas_big(n)
+}
+
+/// Returns the primes below `PRE_FILTER_LIMIT`, which are sieved on the first call only.
+fn small_primes() -> &'static [usize] {
+    static SMALL_PRIMES: OnceLock<Vec<usize>> = OnceLock::new();
+    SMALL_PRIMES.get_or_init(|| BitwiseSieve::new().gen_range(0..PRE_FILTER_LIMIT))
+}
+
+/// Checks whether `n` has a prime factor below `PRE_FILTER_LIMIT` other than itself.
+fn has_small_factor(n: &BigUint) -> bool {
+    small_primes()
+        .iter()
+        .any(|&p| (n % p).is_zero() && *n != BigUint::from(p))
+}
+
+/// Generates a random number with exactly `bits` bits.
+fn random_bits<R: RngCore + ?Sized>(bits: u64, rng: &mut R) -> BigUint {
+    let mut bytes = vec![0; bits.div_ceil(8) as usize];
+    rng.fill_bytes(&mut bytes);
+    let mut n = BigUint::from_bytes_le(&bytes);
+    if !bits.is_multiple_of(8) {
+        n &= (BigUint::one() << bits) - 1_u32;
+    }
+    n.set_bit(bits - 1, true);
+    n
+}
+
+/// Generates a random prime with exactly `bits` bits, uniformly distributed among such primes, by
+/// drawing candidates from `rng` until one passes trial division and `baillie_psw_big`. Returns
+/// None if `bits` is less than 2.
+///
+/// The result only depends on the output of `rng`, so a seeded generator gives reproducible primes.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::{baillie_psw_big, random_prime};
+/// # use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+/// # fn calc() -> Option<()> {
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+/// let p = random_prime(512, &mut rng)?;
+/// assert_eq!(p.bits(), 512);
+/// assert!(baillie_psw_big(&p));
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+/// assert_eq!(random_prime(512, &mut rng)?, p);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn random_prime<R: RngCore + ?Sized>(bits: u64, rng: &mut R) -> Option<BigUint> {
+    if bits < 2 {
+        return None;
+    }
+    loop {
+        let n = random_bits(bits, rng);
+        if !has_small_factor(&n) && baillie_psw_big_unfiltered(&n) {
+            return Some(n);
+        }
+    }
+}
+
+/// Generates a random prime with exactly `bits` bits, returning an error if `bits` is less than 2.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::{try_random_prime, Error};
+/// # use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+/// # fn main() {
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+/// assert_eq!(try_random_prime(64, &mut rng).map(|p| p.bits()), Ok(64));
+/// assert_eq!(try_random_prime(1, &mut rng), Err(Error::InvalidInput));
+/// # }
+/// ```
+pub fn try_random_prime<R: RngCore + ?Sized>(bits: u64, rng: &mut R) -> Result<BigUint, Error> {
+    random_prime(bits, rng).ok_or(Error::InvalidInput)
+}
+
+/// Generates a random safe prime `p = 2q + 1` with prime `q` and exactly `bits` bits, uniformly
+/// distributed among such primes, by drawing candidates for `q` from `rng` until both pass trial
+/// division and `baillie_psw_big`. Returns None if `bits` is less than 3.
+///
+/// # Examples
+/// ```
+/// # use num_bigint::BigUint;
+/// # use prime_number_utils::{baillie_psw_big, random_safe_prime};
+/// # use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+/// # fn calc() -> Option<()> {
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(7);
+/// let p = random_safe_prime(128, &mut rng)?;
+/// assert_eq!(p.bits(), 128);
+/// assert!(baillie_psw_big(&p) && baillie_psw_big(&(p >> 1)));
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn random_safe_prime<R: RngCore + ?Sized>(bits: u64, rng: &mut R) -> Option<BigUint> {
+    if bits < 3 {
+        return None;
+    }
+    loop {
+        let q = random_bits(bits - 1, rng);
+        let p = (&q << 1) + 1_u32;
+        if !has_small_factor(&q)
+            && !has_small_factor(&p)
+            && baillie_psw_big_unfiltered(&q)
+            && baillie_psw_big_unfiltered(&p)
+        {
+            return Some(p);
+        }
+    }
+}
+
+/// Generates a random safe prime with exactly `bits` bits, returning an error if `bits` is less
+/// than 3.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::{try_random_safe_prime, Error};
+/// # use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+/// # fn main() {
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(7);
+/// assert_eq!(try_random_safe_prime(3, &mut rng).map(|p| p.bits()), Ok(3));
+/// assert_eq!(try_random_safe_prime(2, &mut rng), Err(Error::InvalidInput));
+/// # }
+/// ```
+pub fn try_random_safe_prime<R: RngCore + ?Sized>(
+    bits: u64,
+    rng: &mut R,
+) -> Result<BigUint, Error> {
+    random_safe_prime(bits, rng).ok_or(Error::InvalidInput)
+}
@@ -0,0 +1,355 @@
+use crate::{baillie_psw, baillie_psw_big, factorize, factorize::BATCH, BitwiseSieve, GenPrime};
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Primes below this bound are removed from `n - 1` by trial division before Pollard's rho
+/// algorithm is used.
+const TRIAL_DIVISION_LIMIT: usize = 1 << 16;
+
+/// Maximum cycle length tried by Pollard's rho algorithm before `prove_prime`
+/// gives up on a cofactor of `n - 1`.
+const RHO_LIMIT: usize = 1 << 20;
+
+/// Number of polynomials `x^2 + c` tried by Pollard's rho algorithm when a cycle only gives the
+/// trivial factor.
+const RHO_POLYNOMIALS: u32 = 8;
+
+/// Witnesses are searched for among the numbers below this bound.
+const WITNESS_LIMIT: u32 = 1 << 16;
+
+/// A certificate that a number is prime, which `verify_certificate` checks without relying on
+/// probable prime tests beyond the range where Baillie-PSW is known to be deterministic.
+///
+/// With the `serde` feature enabled, certificates implement `Serialize` and `Deserialize`, so they
+/// can be stored and checked later.
+///
+/// # Examples
+/// ```
+/// # use num_bigint::BigUint;
+/// # use prime_number_utils::{prove_prime, verify_certificate, Certificate};
+/// # fn calc() -> Option<()> {
+/// let certificate = prove_prime(&BigUint::from(1_000_000_007_u32))?;
+/// assert_eq!(certificate, Certificate::Small(1_000_000_007));
+///
+/// let mersenne = (BigUint::from(1_u32) << 127) - 1_u32;
+/// let certificate = prove_prime(&mersenne)?;
+/// assert!(matches!(certificate, Certificate::Bls { .. }));
+/// assert_eq!(certificate.n(), mersenne);
+/// assert!(verify_certificate(&certificate));
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Certificate {
+    /// A prime below `2^64`, where Baillie-PSW has no pseudoprimes.
+    Small(u64),
+    /// A Pratt certificate, made of the complete factorization of `n - 1` with certificates for
+    /// its prime factors, and a witness whose order modulo `n` is `n - 1`.
+    Pratt {
+        n: BigUint,
+        witness: BigUint,
+        factors: Vec<(Certificate, u32)>,
+    },
+    /// A Pocklington certificate, made of a factored part `F` of `n - 1` with `F^2 > n`, and a
+    /// witness for each prime factor of `F`.
+    Pocklington {
+        n: BigUint,
+        factors: Vec<WitnessedFactor>,
+    },
+    /// A Brillhart-Lehmer-Selfridge certificate, which is a Pocklington certificate that only needs
+    /// `F^3 >= n`, combined with the condition that `c1^2 - 4 c2` is not a square for
+    /// `n = c2 F^2 + c1 F + 1`.
+    Bls {
+        n: BigUint,
+        factors: Vec<WitnessedFactor>,
+    },
+}
+
+/// A prime power `q^exponent` dividing `n - 1` in a Pocklington or Brillhart-Lehmer-Selfridge
+/// certificate, with a witness `a` such that `a^(n - 1) = 1 (mod n)` and
+/// `gcd(a^((n - 1) / q) - 1, n) = 1`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WitnessedFactor {
+    pub prime: Certificate,
+    pub exponent: u32,
+    pub witness: BigUint,
+}
+
+impl Certificate {
+    /// Returns the number the certificate claims to be prime.
+    pub fn n(&self) -> BigUint {
+        match self {
+            Self::Small(n) => BigUint::from(*n),
+            Self::Pratt { n, .. } | Self::Pocklington { n, .. } | Self::Bls { n, .. } => n.clone(),
+        }
+    }
+}
+
+/// Generates a certificate that `n` is prime, recursively certifying the prime factors of `n - 1`.
+///
+/// Primes below `2^64` get a `Certificate::Small`. Above that, `n - 1` is factored by trial
+/// division and Pollard's rho algorithm until it is factored completely, which gives a Pratt
+/// certificate, or until the factored part is large enough for a Pocklington or
+/// Brillhart-Lehmer-Selfridge certificate. Returns None if `n` is not prime or `n - 1` could not be
+/// factored far enough.
+///
+/// Pollard's rho algorithm gives up after `2^20` steps, so it only finds prime factors of up to
+/// about 40 bits, and a cofactor it cannot split is left out of the factored part. The proof
+/// therefore needs the factors found to make up about a third of the bits of `n - 1`. This holds
+/// for primes like `k 2^m + 1` of any size and for nearly all random primes up to 100 bits, but
+/// only for about half of the random primes of 200 to 250 bits, and a failure takes seconds.
+///
+/// # Examples
+/// ```
+/// # use num_bigint::BigUint;
+/// # use prime_number_utils::{prove_prime, verify_certificate, Certificate};
+/// # fn main() {
+/// let n = (BigUint::from(45_u32) << 200) + 1_u32;
+/// let certificate = prove_prime(&n).unwrap();
+/// assert!(matches!(certificate, Certificate::Pratt { .. }));
+/// assert!(verify_certificate(&certificate));
+/// assert_eq!(prove_prime(&(n + 2_u32)), None);
+/// # }
+/// ```
+pub fn prove_prime(n: &BigUint) -> Option<Certificate> {
+    if let Some(m) = n.to_u64() {
+        return baillie_psw_u64(m).then_some(Certificate::Small(m));
+    }
+    if !baillie_psw_big(n) {
+        return None;
+    }
+    let mut n1 = n - 1_u32;
+    let mut factors: BTreeMap<BigUint, u32> = BTreeMap::new();
+    for q in BitwiseSieve::new().gen_range(0..TRIAL_DIVISION_LIMIT) {
+        while (&n1 % q).is_zero() {
+            n1 /= q;
+            *factors.entry(BigUint::from(q)).or_default() += 1;
+        }
+    }
+    let mut unfactored = if n1.is_one() { vec![] } else { vec![n1] };
+    // Cofactors that Pollard's rho algorithm could not split, which are left out of the factored
+    // part while the other cofactors are still tried.
+    let mut unsplit = vec![];
+    loop {
+        let part: BigUint = factors.iter().map(|(q, &e)| q.pow(e)).product();
+        if unfactored.is_empty() && unsplit.is_empty() {
+            let factors: Vec<(Certificate, u32)> = factors
+                .into_iter()
+                .map(|(q, e)| Some((prove_prime(&q)?, e)))
+                .collect::<Option<_>>()?;
+            let n1 = n - 1_u32;
+            let witness = (2..WITNESS_LIMIT).map(BigUint::from).find(|a| {
+                a.modpow(&n1, n).is_one()
+                    && factors
+                        .iter()
+                        .all(|(q, _)| !a.modpow(&(&n1 / q.n()), n).is_one())
+            })?;
+            return Some(Certificate::Pratt {
+                n: n.clone(),
+                witness,
+                factors,
+            });
+        }
+        if &part * &part > *n {
+            return Some(Certificate::Pocklington {
+                n: n.clone(),
+                factors: witnessed_factors(n, &factors)?,
+            });
+        }
+        if &part * &part * &part >= *n && !bls_discriminant_is_square(n, &part) {
+            return Some(Certificate::Bls {
+                n: n.clone(),
+                factors: witnessed_factors(n, &factors)?,
+            });
+        }
+        let m = unfactored.pop()?;
+        if baillie_psw_big(&m) {
+            *factors.entry(m).or_default() += 1;
+        } else if let Some(m) = m.to_u64() {
+            for q in factorize(m) {
+                *factors.entry(BigUint::from(q)).or_default() += 1;
+            }
+        } else if let Some(d) = pollard_rho_big(&m) {
+            unfactored.push(&m / &d);
+            unfactored.push(d);
+        } else {
+            unsplit.push(m);
+        }
+    }
+}
+
+/// Baillie-PSW for `n` below `2^64`, which may not fit in `usize`.
+fn baillie_psw_u64(n: u64) -> bool {
+    match usize::try_from(n) {
+        Ok(n) => baillie_psw(n),
+        Err(_) => baillie_psw_big(&BigUint::from(n)),
+    }
+}
+
+/// Certifies the prime factors `q` of a factored part of `n - 1` and finds a witness for each.
+fn witnessed_factors(
+    n: &BigUint,
+    factors: &BTreeMap<BigUint, u32>,
+) -> Option<Vec<WitnessedFactor>> {
+    factors
+        .iter()
+        .map(|(q, &exponent)| {
+            let witness = (2..WITNESS_LIMIT)
+                .map(BigUint::from)
+                .find(|a| is_pocklington_witness(n, q, a))?;
+            Some(WitnessedFactor {
+                prime: prove_prime(q)?,
+                exponent,
+                witness,
+            })
+        })
+        .collect()
+}
+
+/// Checks whether `a^(n - 1) = 1 (mod n)` and `gcd(a^((n - 1) / q) - 1, n) = 1`.
+fn is_pocklington_witness(n: &BigUint, q: &BigUint, a: &BigUint) -> bool {
+    let n1 = n - 1_u32;
+    if !a.modpow(&n1, n).is_one() {
+        return false;
+    }
+    let x = a.modpow(&(&n1 / q), n);
+    ((x + &n1) % n).gcd(n).is_one()
+}
+
+/// Checks whether `c1^2 - 4 c2` is a square for `n = c2 F^2 + c1 F + 1` with `0 <= c1 < F`.
+fn bls_discriminant_is_square(n: &BigUint, part: &BigUint) -> bool {
+    let (c2, r) = (n - 1_u32).div_rem(&(part * part));
+    let c1 = r / part;
+    let (square, c2) = (&c1 * &c1, c2 << 2);
+    square >= c2 && {
+        let d = square - c2;
+        &d.sqrt() * &d.sqrt() == d
+    }
+}
+
+/// Finds a nontrivial factor of an odd composite `n` with Pollard's rho algorithm, using Brent's
+/// cycle detection, returning None if none was found within `RHO_LIMIT` steps.
+fn pollard_rho_big(n: &BigUint) -> Option<BigUint> {
+    let diff = |x: &BigUint, y: &BigUint| if x > y { x - y } else { y - x };
+    for c in 1..=RHO_POLYNOMIALS {
+        let f = |x: &BigUint| (x * x + c) % n;
+        let (mut x, mut y, mut ys) = (BigUint::one(), BigUint::one(), BigUint::one());
+        let (mut g, mut r, mut q) = (BigUint::one(), 1, BigUint::one());
+        while g.is_one() && r <= RHO_LIMIT {
+            x = y.clone();
+            for _ in 0..r {
+                y = f(&y);
+            }
+            let mut k = 0;
+            while k < r && g.is_one() {
+                ys = y.clone();
+                // Multiply the differences together so that one gcd covers the whole batch.
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(&y);
+                    q = q * diff(&x, &y) % n;
+                }
+                g = q.gcd(n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+        if g == *n {
+            // The batch overshot, so retrace it one step at a time.
+            loop {
+                ys = f(&ys);
+                g = diff(&x, &ys).gcd(n);
+                if !g.is_one() {
+                    break;
+                }
+            }
+        }
+        if g.is_one() {
+            return None;
+        }
+        if g != *n {
+            return Some(g);
+        }
+    }
+    None
+}
+
+/// Calculates the factored part `F` of `n - 1` after checking that it divides `n - 1`, that its
+/// prime factors are certified and that the Pocklington witnesses are valid, which proves that
+/// every prime factor of `n` is `1 (mod F)`.
+fn verified_part(n: &BigUint, factors: &[WitnessedFactor]) -> Option<BigUint> {
+    if n < &BigUint::from(2_u32) {
+        return None;
+    }
+    let part: BigUint = factors
+        .iter()
+        .map(|factor| factor.prime.n().pow(factor.exponent))
+        .product();
+    let valid = factors.iter().all(|factor| {
+        verify_certificate(&factor.prime)
+            && is_pocklington_witness(n, &factor.prime.n(), &factor.witness)
+    }) && ((n - 1_u32) % &part).is_zero();
+    valid.then_some(part)
+}
+
+/// Checks a primality certificate, recursively checking the certificates of the prime factors of
+/// `n - 1` it contains.
+///
+/// # Examples
+/// ```
+/// # use num_bigint::BigUint;
+/// # use prime_number_utils::{verify_certificate, Certificate};
+/// # fn main() {
+/// // 3 generates the multiplicative group modulo 7, and 6 = 2 * 3.
+/// let certificate = Certificate::Pratt {
+///     n: BigUint::from(7_u32),
+///     witness: BigUint::from(3_u32),
+///     factors: vec![(Certificate::Small(2), 1), (Certificate::Small(3), 1)],
+/// };
+/// assert!(verify_certificate(&certificate));
+///
+/// // 2 has order 3 modulo 7.
+/// let certificate = Certificate::Pratt {
+///     n: BigUint::from(7_u32),
+///     witness: BigUint::from(2_u32),
+///     factors: vec![(Certificate::Small(2), 1), (Certificate::Small(3), 1)],
+/// };
+/// assert!(!verify_certificate(&certificate));
+/// assert!(!verify_certificate(&Certificate::Small(9)));
+/// # }
+/// ```
+pub fn verify_certificate(certificate: &Certificate) -> bool {
+    match certificate {
+        Certificate::Small(n) => baillie_psw_u64(*n),
+        Certificate::Pratt {
+            n,
+            witness,
+            factors,
+        } => {
+            if n < &BigUint::from(2_u32) {
+                return false;
+            }
+            let n1 = n - 1_u32;
+            let product: BigUint = factors.iter().map(|(q, e)| q.n().pow(*e)).product();
+            product == n1
+                && witness.modpow(&n1, n).is_one()
+                && factors.iter().all(|(q, _)| {
+                    verify_certificate(q) && !witness.modpow(&(&n1 / q.n()), n).is_one()
+                })
+        }
+        Certificate::Pocklington { n, factors } => {
+            verified_part(n, factors).is_some_and(|part| &part * &part > *n)
+        }
+        Certificate::Bls { n, factors } => verified_part(n, factors).is_some_and(|part| {
+            &part * &part * &part >= *n && !bls_discriminant_is_square(n, &part)
+        }),
+    }
+}
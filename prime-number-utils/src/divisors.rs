@@ -0,0 +1,220 @@
+use crate::{factorize::prime_powers, Error};
+use std::cmp::Ordering;
+
+/// Calculates the divisors in ascending order from the prime factorization.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::divisors;
+/// # fn main() {
+/// assert_eq!(divisors(36), vec![1, 2, 3, 4, 6, 9, 12, 18, 36]);
+/// assert_eq!(divisors(1), vec![1]);
+/// assert_eq!(divisors(0), vec![]);
+/// # }
+/// ```
+pub fn divisors(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return vec![];
+    }
+    let mut divisors = vec![1];
+    for (p, k) in prime_powers(n) {
+        let count = divisors.len();
+        let mut power = 1;
+        for _ in 0..k {
+            power *= p;
+            for i in 0..count {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+/// Calculates the number of divisors from the prime factorization.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::divisor_count;
+/// # fn main() {
+/// assert_eq!(divisor_count(36), 9);
+/// assert_eq!(divisor_count(1), 1);
+/// assert_eq!(divisor_count(0), 0);
+/// # }
+/// ```
+pub fn divisor_count(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    prime_powers(n).iter().map(|&(_, k)| k as u64 + 1).product()
+}
+
+/// Calculates the divisor function `σk(n)`, the sum of the k-th powers of the divisors, from the
+/// prime factorization, returning None if overflow occured.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::divisor_sigma;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(divisor_sigma(12, 0)?, 6);
+/// assert_eq!(divisor_sigma(12, 1)?, 28);
+/// assert_eq!(divisor_sigma(12, 2)?, 210);
+/// assert_eq!(divisor_sigma(u64::MAX, 1), None);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn divisor_sigma(n: u64, k: u32) -> Option<u64> {
+    if n == 0 {
+        return Some(0);
+    }
+    prime_powers(n)
+        .into_iter()
+        .try_fold(1_u64, |sigma, (p, e)| {
+            // σk is multiplicative with σk(p^e) = 1 + p^k + ... + p^(ek).
+            let pk = p.checked_pow(k)?;
+            let (mut sum, mut power) = (1_u64, 1_u64);
+            for _ in 0..e {
+                power = power.checked_mul(pk)?;
+                sum = sum.checked_add(power)?;
+            }
+            sigma.checked_mul(sum)
+        })
+}
+
+/// Calculates the divisor function `σk(n)`, returning an error if overflow occured.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::{try_divisor_sigma, Error};
+/// # fn main() {
+/// assert_eq!(try_divisor_sigma(12, 1), Ok(28));
+/// assert_eq!(try_divisor_sigma(12, 40), Err(Error::Overflow));
+/// # }
+/// ```
+pub fn try_divisor_sigma(n: u64, k: u32) -> Result<u64, Error> {
+    divisor_sigma(n, k).ok_or(Error::Overflow)
+}
+
+/// Calculates the sum of the proper divisors, the divisors other than `n` itself, returning None if
+/// overflow occured.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::proper_divisor_sum;
+/// # fn calc() -> Option<()> {
+/// assert_eq!(proper_divisor_sum(220)?, 284);
+/// assert_eq!(proper_divisor_sum(284)?, 220);
+/// assert_eq!(proper_divisor_sum(1)?, 0);
+/// # Some(())
+/// # }
+/// # fn main() {
+/// # calc().unwrap();
+/// # }
+/// ```
+pub fn proper_divisor_sum(n: u64) -> Option<u64> {
+    Some(divisor_sigma(n, 1)? - n)
+}
+
+/// Compares `σ(n)` with `2n` without overflowing, which is None if `n` is zero.
+fn abundance(n: u64) -> Option<Ordering> {
+    if n == 0 {
+        return None;
+    }
+    let sigma: u128 = prime_powers(n)
+        .into_iter()
+        .map(|(p, k)| (0..=k).map(|i| (p as u128).pow(i)).sum::<u128>())
+        .product();
+    Some(sigma.cmp(&(2 * n as u128)))
+}
+
+/// Checks whether `n` is a perfect number, equal to the sum of its proper divisors.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::is_perfect;
+/// # fn main() {
+/// assert!(is_perfect(8_128));
+/// assert!(is_perfect(2_305_843_008_139_952_128));
+/// assert!(!is_perfect(12));
+/// # }
+/// ```
+pub fn is_perfect(n: u64) -> bool {
+    abundance(n) == Some(Ordering::Equal)
+}
+
+/// Checks whether `n` is an abundant number, less than the sum of its proper divisors.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::is_abundant;
+/// # fn main() {
+/// assert!(is_abundant(12));
+/// assert!(!is_abundant(28));
+/// # }
+/// ```
+pub fn is_abundant(n: u64) -> bool {
+    abundance(n) == Some(Ordering::Greater)
+}
+
+/// Checks whether `n` is a deficient number, greater than the sum of its proper divisors.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::is_deficient;
+/// # fn main() {
+/// assert!(is_deficient(1));
+/// assert!(is_deficient(9));
+/// assert!(!is_deficient(6));
+/// # }
+/// ```
+pub fn is_deficient(n: u64) -> bool {
+    abundance(n) == Some(Ordering::Less)
+}
+
+/// Calculates the amicable partner of `n`, the `m != n` with `s(n) = m` and `s(m) = n` for the
+/// proper divisor sum `s`, returning None if there is none.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::amicable_partner;
+/// # fn main() {
+/// assert_eq!(amicable_partner(1_184), Some(1_210));
+/// assert_eq!(amicable_partner(1_210), Some(1_184));
+/// assert_eq!(amicable_partner(6), None);
+/// # }
+/// ```
+pub fn amicable_partner(n: u64) -> Option<u64> {
+    let m = proper_divisor_sum(n)?;
+    (m != n && m != 0 && proper_divisor_sum(m)? == n).then_some(m)
+}
+
+/// Calculates the amicable pairs `(a, b)` with `a < b <= limit` in ascending order, using a sieve
+/// of proper divisor sums in `O(limit log limit)` time.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::amicable_pairs;
+/// # fn main() {
+/// assert_eq!(
+///     amicable_pairs(10_000),
+///     vec![(220, 284), (1_184, 1_210), (2_620, 2_924), (5_020, 5_564), (6_232, 6_368)]
+/// );
+/// # }
+/// ```
+pub fn amicable_pairs(limit: usize) -> Vec<(usize, usize)> {
+    let mut sums = vec![0; limit + 1];
+    for d in 1..=limit / 2 {
+        for multiple in (2 * d..=limit).step_by(d) {
+            sums[multiple] += d;
+        }
+    }
+    (1..=limit)
+        .filter_map(|a| {
+            let b = sums[a];
+            (a < b && b <= limit && sums[b] == a).then_some((a, b))
+        })
+        .collect()
+}
@@ -0,0 +1,111 @@
+use crate::{baillie_psw, baillie_psw::MontgomeryMultiplication};
+use number_utils::NumberUtils;
+
+/// Primes below 100, removed by trial division before Pollard's rho algorithm is used.
+const SMALL_PRIMES: [u64; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Number of steps between the gcd calculations of Brent's cycle detection.
+pub(crate) const BATCH: usize = 128;
+
+/// Finds a nontrivial factor of an odd composite `n` with Pollard's rho algorithm, using Brent's
+/// cycle detection and Montgomery modular multiplication.
+fn pollard_rho(n: u64) -> u64 {
+    let mont = MontgomeryMultiplication::new(n as usize);
+    let n = n as usize;
+    for c in 1.. {
+        let c = mont.ar(c);
+        let f = |x: usize| mont.add(mont.mrmul(x, x), c);
+        let (mut x, mut y, mut ys) = (0, mont.r, mont.r);
+        let (mut g, mut r, mut q) = (1, 1, mont.r);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                // Multiply the differences together so that one gcd covers the whole batch.
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mont.mrmul(q, x.abs_diff(y));
+                }
+                g = q.gcd(n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+        if g == n {
+            // The batch overshot, so retrace it one step at a time.
+            loop {
+                ys = f(ys);
+                g = x.abs_diff(ys).gcd(n);
+                if g != 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g as u64;
+        }
+    }
+    unreachable!()
+}
+
+/// Pushes the prime factors of `n`, which has no prime factors below 100, onto `prime_factors`.
+fn factorize_large(n: u64, prime_factors: &mut Vec<u64>) {
+    if n == 1 {
+        return;
+    }
+    if baillie_psw(n as usize) {
+        prime_factors.push(n);
+        return;
+    }
+    let d = pollard_rho(n);
+    factorize_large(d, prime_factors);
+    factorize_large(n / d, prime_factors);
+}
+
+/// Calculates prime factorization in ascending order using trial division by primes below 100,
+/// then Pollard's rho algorithm with Baillie-PSW to recognize the prime factors.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::factorize;
+/// # fn main() {
+/// assert_eq!(factorize(48), vec![2, 2, 2, 2, 3]);
+/// assert_eq!(factorize(600_851_475_143), vec![71, 839, 1_471, 6_857]);
+/// assert_eq!(factorize(4_611_686_014_132_420_609), vec![2_147_483_647, 2_147_483_647]);
+/// assert_eq!(factorize(1), vec![]);
+/// # }
+/// ```
+pub fn factorize(mut n: u64) -> Vec<u64> {
+    let mut prime_factors = vec![];
+    if n == 0 {
+        return prime_factors;
+    }
+    for p in SMALL_PRIMES {
+        while n.is_multiple_of(p) {
+            prime_factors.push(p);
+            n /= p;
+        }
+    }
+    factorize_large(n, &mut prime_factors);
+    prime_factors.sort_unstable();
+    prime_factors
+}
+
+/// Calculates the prime factorization as pairs of distinct primes in ascending order and their
+/// exponents.
+pub(crate) fn prime_powers(n: u64) -> Vec<(u64, u32)> {
+    let mut prime_powers: Vec<(u64, u32)> = vec![];
+    for p in factorize(n) {
+        match prime_powers.last_mut() {
+            Some((q, k)) if *q == p => *k += 1,
+            _ => prime_powers.push((p, 1)),
+        }
+    }
+    prime_powers
+}
@@ -15,19 +15,31 @@
 //! assert!(primes.iter().all(|&n| baillie_psw(n)));
 //! # }
 //! ```
+mod arithmetic_progression;
 mod baillie_psw;
 #[cfg(feature = "num-bigint")]
 pub mod bigint;
 mod bitwise_sieve;
+#[cfg(feature = "num-bigint")]
+mod certificate;
+mod divisors;
+mod factorize;
 mod gen_prime;
 mod linear_sieve;
+mod modular;
+mod prime_families;
+mod prime_tuples;
 mod segmented_sieve;
 mod sieve_of_atkin;
 mod sieve_of_eratosthenes;
 mod sieve_of_sundaram;
 mod test;
 
+pub use number_utils::Error;
+
 pub use crate::{
-    baillie_psw::*, bigint::*, bitwise_sieve::*, gen_prime::GenPrime, linear_sieve::*,
-    segmented_sieve::*, sieve_of_atkin::*, sieve_of_eratosthenes::*, sieve_of_sundaram::*,
+    arithmetic_progression::*, baillie_psw::*, bigint::*, bitwise_sieve::*, certificate::*,
+    divisors::*, factorize::*, gen_prime::GenPrime, linear_sieve::*, modular::*, prime_families::*,
+    prime_tuples::*, segmented_sieve::*, sieve_of_atkin::*, sieve_of_eratosthenes::*,
+    sieve_of_sundaram::*,
 };
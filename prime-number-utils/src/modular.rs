@@ -0,0 +1,434 @@
+use crate::{baillie_psw::MontgomeryMultiplication, factorize::prime_powers};
+use number_utils::NumberUtils;
+use std::collections::HashMap;
+
+/// Two-adicity of `p - 1` from which `mod_sqrt` uses Cipolla's algorithm instead of
+/// Tonelli-Shanks, whose cost grows with its square.
+const CIPOLLA_MIN_TWO_ADICITY: u32 = 20;
+
+/// Calculates `a * b % m` without overflowing.
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// Calculates `(a + b) % m` without overflowing.
+fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 + b as u128) % m as u128) as u64
+}
+
+/// Calculates `(a - b) % m` for `a, b < m` without overflowing.
+fn sub_mod(a: u64, b: u64, m: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+/// Calculates the x with `x % m1 == r1` and `x % m2 == r2` for coprime `m1` and `m2` whose product
+/// does not overflow.
+fn crt(r1: u64, m1: u64, r2: u64, m2: u64) -> u64 {
+    let inverse = mod_inverse(m1 % m2, m2).expect("moduli must be coprime");
+    r1 + m1 * mul_mod(sub_mod(r2, r1 % m2, m2), inverse, m2)
+}
+
+/// Calculates Euler's totient from the prime factorization.
+fn totient(prime_powers: &[(u64, u32)]) -> u64 {
+    prime_powers
+        .iter()
+        .map(|&(p, k)| p.pow(k - 1) * (p - 1))
+        .product()
+}
+
+/// Calculates the Carmichael function from the prime factorization.
+fn carmichael(prime_powers: &[(u64, u32)]) -> u64 {
+    prime_powers
+        .iter()
+        .map(|&(p, k)| match (p, k) {
+            (2, 3..) => 1 << (k - 2),
+            _ => p.pow(k - 1) * (p - 1),
+        })
+        .fold(1, |lambda, n| lambda.lcm(n))
+}
+
+/// Calculates Euler's totient, the number of integers in `1..=n` coprime to `n`, using prime
+/// factorization. Returns zero if `n` is zero.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::euler_phi;
+/// # fn main() {
+/// assert_eq!(euler_phi(36), 12);
+/// assert_eq!(euler_phi(1_000_000_007), 1_000_000_006);
+/// # }
+/// ```
+pub fn euler_phi(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    totient(&prime_powers(n))
+}
+
+/// Calculates the Carmichael function, the least `m` with `a^m = 1` modulo `n` for every `a`
+/// coprime to `n`, using prime factorization. Returns zero if `n` is zero.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::carmichael_lambda;
+/// # fn main() {
+/// assert_eq!(carmichael_lambda(36), 6);
+/// assert_eq!(carmichael_lambda(561), 80);
+/// assert_eq!(carmichael_lambda(1 << 40), 1 << 38);
+/// # }
+/// ```
+pub fn carmichael_lambda(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    carmichael(&prime_powers(n))
+}
+
+/// Calculates the multiplicative order, the least positive `k` with `a^k = 1` modulo `n`, by
+/// removing prime factors from the Carmichael function while the power stays one. Returns None if
+/// `a` and `n` are not coprime or `n` is zero.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::multiplicative_order;
+/// # fn main() {
+/// assert_eq!(multiplicative_order(2, 7), Some(3));
+/// assert_eq!(multiplicative_order(5, 1_000_000_007), Some(1_000_000_006));
+/// assert_eq!(multiplicative_order(6, 9), None);
+/// # }
+/// ```
+pub fn multiplicative_order(a: u64, n: u64) -> Option<u64> {
+    if n == 0 || a.gcd(n) != 1 {
+        return None;
+    }
+    let mut order = carmichael_lambda(n);
+    for (q, _) in prime_powers(order) {
+        while order.is_multiple_of(q) && mod_pow(a, order / q, n) == 1 % n {
+            order /= q;
+        }
+    }
+    Some(order)
+}
+
+/// Calculates modular exponentiation `base^exp % modulus` using Montgomery modular multiplication
+/// for odd moduli and binary exponentiation otherwise.
+///
+/// # Panics
+/// Panics if `modulus` is zero.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::mod_pow;
+/// # fn main() {
+/// assert_eq!(mod_pow(4, 13, 497), 445);
+/// assert_eq!(mod_pow(2, 1_000_000, 1 << 40), 0);
+/// assert_eq!(mod_pow(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+/// # }
+/// ```
+pub fn mod_pow(base: u64, exp: u64, modulus: u64) -> u64 {
+    assert!(modulus != 0, "modulus must not be zero");
+    if modulus == 1 {
+        return 0;
+    }
+    if modulus % 2 == 1 {
+        let mont = MontgomeryMultiplication::new(modulus as usize);
+        return mont.mr(mont.powir(mont.ar(base as usize % mont.n), exp as usize)) as u64;
+    }
+    let (mut base, mut exp, mut result) = (base % modulus, exp, 1);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Calculates the modular multiplicative inverse of `a` modulo `m` using the extended Euclidean
+/// algorithm, returning None if `a` and `m` are not coprime or `m` is zero.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::mod_inverse;
+/// # fn main() {
+/// assert_eq!(mod_inverse(3, 11), Some(4));
+/// assert_eq!(mod_inverse(4, 10), None);
+/// # }
+/// ```
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    let (mut r0, mut r1) = (m as i128, (a % m) as i128);
+    let (mut t0, mut t1) = (0_i128, 1_i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    (r0 == 1).then(|| t0.rem_euclid(m as i128) as u64)
+}
+
+/// Calculates a square root of `a` modulo `p` with Tonelli-Shanks, given a quadratic residue `a`
+/// and an odd prime `p` with `p - 1 = q 2^s` for odd `q`.
+fn tonelli_shanks(a: u64, p: u64, s: u32) -> u64 {
+    let q = (p - 1) >> s;
+    let z = (2..)
+        .find(|&z| mod_pow(z, (p - 1) / 2, p) == p - 1)
+        .expect("half of the residues are non-residues");
+    let (mut m, mut c) = (s, mod_pow(z, q, p));
+    let (mut t, mut r) = (mod_pow(a, q, p), mod_pow(a, q.div_ceil(2), p));
+    while t != 1 {
+        // Find the least i with t^(2^i) = 1.
+        let (mut i, mut t2) = (0, t);
+        while t2 != 1 {
+            t2 = mul_mod(t2, t2, p);
+            i += 1;
+        }
+        let b = (0..m - i - 1).fold(c, |b, _| mul_mod(b, b, p));
+        (m, c) = (i, mul_mod(b, b, p));
+        (t, r) = (mul_mod(t, c, p), mul_mod(r, b, p));
+    }
+    r
+}
+
+/// Calculates a square root of `a` modulo `p` with Cipolla's algorithm, given a quadratic residue
+/// `a` and an odd prime `p`.
+fn cipolla(a: u64, p: u64) -> u64 {
+    // Find t with a non-residue w = t^2 - a, and calculate (t + sqrt(w))^((p + 1) / 2).
+    let (t, w) = (1..)
+        .map(|t| (t, add_mod(mul_mod(t, t, p), p - a, p)))
+        .find(|&(_, w)| mod_pow(w, (p - 1) / 2, p) == p - 1)
+        .expect("half of the residues are non-residues");
+    let mul = |(x0, x1): (u64, u64), (y0, y1): (u64, u64)| {
+        (
+            add_mod(mul_mod(x0, y0, p), mul_mod(mul_mod(x1, y1, p), w, p), p),
+            add_mod(mul_mod(x0, y1, p), mul_mod(x1, y0, p), p),
+        )
+    };
+    let (mut base, mut exp, mut result) = ((t, 1), p.div_ceil(2), (1, 0));
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul(result, base);
+        }
+        base = mul(base, base);
+        exp >>= 1;
+    }
+    result.0
+}
+
+/// Calculates a square root of a unit `a` modulo a prime power `p^k`, returning None if there is
+/// none.
+fn sqrt_unit_mod_prime_power(a: u64, p: u64, k: u32) -> Option<u64> {
+    if p == 2 {
+        return match k {
+            1 => Some(1),
+            2 => (a % 4 == 1).then_some(1),
+            _ if a % 8 != 1 => None,
+            _ => {
+                // Of the roots x and x + 2^(j - 1) modulo 2^j, one is also a root modulo 2^(j + 1).
+                let mut x = 1_u64;
+                for j in 3..k {
+                    let mask = (1_u128 << (j + 1)) - 1;
+                    if (x as u128 * x as u128) & mask != a as u128 & mask {
+                        x += 1 << (j - 1);
+                    }
+                }
+                Some(x)
+            }
+        };
+    }
+    let b = a % p;
+    if mod_pow(b, (p - 1) / 2, p) != 1 {
+        return None;
+    }
+    let s = (p - 1).trailing_zeros();
+    let mut x = match s {
+        1 => mod_pow(b, (p + 1) / 4, p),
+        s if s >= CIPOLLA_MIN_TWO_ADICITY => cipolla(b, p),
+        s => tonelli_shanks(b, p, s),
+    };
+    // Hensel lifting x -> x - (x^2 - a) / 2x from p^j to p^(j + 1).
+    let mut modulus = p;
+    for _ in 1..k {
+        modulus *= p;
+        let f = sub_mod(mul_mod(x, x, modulus), a % modulus, modulus);
+        let inverse = mod_inverse(add_mod(x, x, modulus), modulus)?;
+        x = sub_mod(x, mul_mod(f, inverse, modulus), modulus);
+    }
+    Some(x)
+}
+
+/// Calculates a square root of `a` modulo a prime power `p^k`, returning None if there is none.
+fn sqrt_mod_prime_power(a: u64, p: u64, k: u32) -> Option<u64> {
+    let a = a % p.pow(k);
+    if a == 0 {
+        return Some(0);
+    }
+    // a = p^e b for a unit b has a root p^(e / 2) y for even e and y^2 = b modulo p^(k - e).
+    let (mut e, mut b) = (0, a);
+    while b % p == 0 {
+        b /= p;
+        e += 1;
+    }
+    if e % 2 == 1 {
+        return None;
+    }
+    Some(sqrt_unit_mod_prime_power(b, p, k - e)? * p.pow(e / 2))
+}
+
+/// Calculates a square root of `a` modulo `m`, returning None if there is none or `m` is zero.
+///
+/// Roots modulo odd primes use Tonelli-Shanks, or Cipolla's algorithm if `p - 1` is divisible by a
+/// large power of two, and are lifted to prime powers with Hensel's lemma. Roots modulo the prime
+/// power factors of `m` are combined with the Chinese remainder theorem. Of the roots `x` and
+/// `m - x`, the smaller one is returned.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::mod_sqrt;
+/// # fn main() {
+/// assert_eq!(mod_sqrt(10, 13), Some(6));
+/// assert_eq!(mod_sqrt(5, 13), None);
+/// assert_eq!(mod_sqrt(4, 45).map(|x| x * x % 45), Some(4));
+/// # }
+/// ```
+pub fn mod_sqrt(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    let (mut x, mut modulus) = (0, 1);
+    for (p, k) in prime_powers(m) {
+        let pk = p.pow(k);
+        x = crt(x, modulus, sqrt_mod_prime_power(a, p, k)?, pk);
+        modulus *= pk;
+    }
+    Some(x.min(m - x))
+}
+
+/// Calculates the least x in `0..order` with `g^x = h` modulo `m` using baby-step giant-step,
+/// given `g` coprime to `m`.
+fn baby_step_giant_step(g: u64, h: u64, order: u64, m: u64) -> Option<u64> {
+    let mut s = (order as f64).sqrt() as u64;
+    while (s as u128 * s as u128) < order as u128 {
+        s += 1;
+    }
+    let mut baby_steps = HashMap::new();
+    let mut e = 1 % m;
+    for j in 0..s {
+        baby_steps.entry(e).or_insert(j);
+        e = mul_mod(e, g, m);
+    }
+    let giant_step = mod_pow(mod_inverse(g, m)?, s, m);
+    let mut y = h;
+    for i in 0..s {
+        if let Some(j) = baby_steps.get(&y) {
+            return Some(i * s + j);
+        }
+        y = mul_mod(y, giant_step, m);
+    }
+    None
+}
+
+/// Calculates the least x with `g^x = h` modulo `m` for `g` coprime to `m` using Pohlig-Hellman.
+fn pohlig_hellman(g: u64, h: u64, m: u64) -> Option<u64> {
+    let n = multiplicative_order(g, m)?;
+    let (mut x, mut modulus) = (0, 1);
+    for (q, e) in prime_powers(n) {
+        // Solve in the subgroup of order q^e one base q digit at a time.
+        let qe = q.pow(e);
+        let (gi, hi) = (mod_pow(g, n / qe, m), mod_pow(h, n / qe, m));
+        let gamma = mod_pow(gi, qe / q, m);
+        let (mut xi, mut qj) = (0, 1);
+        for _ in 0..e {
+            let t = mul_mod(mod_pow(gi, qe - xi, m), hi, m);
+            let t = mod_pow(t, qe / q / qj, m);
+            xi += baby_step_giant_step(gamma, t, q, m)? * qj;
+            qj *= q;
+        }
+        x = crt(x, modulus, xi, qe);
+        modulus *= qe;
+    }
+    // h may not lie in the subgroup generated by g.
+    (mod_pow(g, x, m) == h).then_some(x)
+}
+
+/// Calculates the discrete logarithm, the least x with `g^x = h` modulo `m`, returning None if
+/// there is none or `m` is zero.
+///
+/// Common factors of `g` and `m` are divided out first, then the problem is split over the prime
+/// power factors of the order of `g` with Pohlig-Hellman, each solved with baby-step giant-step in
+/// `O(sqrt(q))` time for the largest prime factor `q` of the order.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::discrete_log;
+/// # fn main() {
+/// assert_eq!(discrete_log(3, 13, 17), Some(4));
+/// assert_eq!(discrete_log(2, 3, 7), None);
+/// assert_eq!(discrete_log(6, 0, 36), Some(2));
+/// # }
+/// ```
+pub fn discrete_log(g: u64, h: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    let (mut g, mut h, mut m) = (g % m, h % m, m);
+    // Reduce to k g^x = h modulo m with g coprime to m, where k is also coprime to m.
+    let (mut k, mut offset) = (1 % m, 0);
+    loop {
+        let d = g.gcd(m);
+        if d == 1 {
+            break;
+        }
+        if h == k {
+            return Some(offset);
+        }
+        if h % d != 0 {
+            return None;
+        }
+        (h, m) = (h / d, m / d);
+        k = mul_mod(k, g / d, m);
+        g %= m;
+        offset += 1;
+    }
+    let h = mul_mod(h, mod_inverse(k, m)?, m);
+    Some(offset + pohlig_hellman(g, h, m)?)
+}
+
+/// Calculates the least primitive root modulo `m`, returning None if there is none.
+///
+/// Primitive roots exist only modulo 1, 2, 4, `p^k` and `2p^k` for odd primes `p`. Candidates are
+/// checked against the prime factors of Euler's totient.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::primitive_root;
+/// # fn main() {
+/// assert_eq!(primitive_root(7), Some(3));
+/// assert_eq!(primitive_root(1_000_000_007), Some(5));
+/// assert_eq!(primitive_root(8), None);
+/// # }
+/// ```
+pub fn primitive_root(m: u64) -> Option<u64> {
+    match m {
+        0 => return None,
+        1 => return Some(0),
+        2 => return Some(1),
+        4 => return Some(3),
+        _ => {}
+    }
+    let powers = prime_powers(m);
+    match powers[..] {
+        [(p, _)] | [(2, 1), (p, _)] if p != 2 => {}
+        _ => return None,
+    }
+    let phi = totient(&powers);
+    let factors: Vec<u64> = prime_powers(phi).into_iter().map(|(q, _)| q).collect();
+    (2..m).find(|&g| g.gcd(m) == 1 && factors.iter().all(|&q| mod_pow(g, phi / q, m) != 1))
+}
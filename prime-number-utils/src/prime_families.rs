@@ -0,0 +1,121 @@
+use crate::{baillie_psw, factorize, PrimeTuples};
+use number_utils::NumberUtils;
+use std::ops::Range;
+
+/// Families of primes defined by a property beyond primality, with digits taken in base 10.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PrimeFamily {
+    /// Primes `p` with `2p + 1` also prime.
+    SophieGermain,
+    /// Primes `p` with `(p - 1) / 2` also prime.
+    Safe,
+    /// Primes `p` with `p + 2` either prime or a product of two primes.
+    Chen,
+    /// Primes whose digits read the same backward as forward.
+    Palindromic,
+    /// Primes whose digit reversal is a different prime.
+    Emirp,
+    /// Primes all of whose digit rotations are prime.
+    Circular,
+}
+
+impl PrimeFamily {
+    /// Checks whether `p` is a prime in the family, using Baillie-PSW for primality.
+    ///
+    /// # Examples
+    /// ```
+    /// # use prime_number_utils::PrimeFamily;
+    /// # fn main() {
+    /// assert!(PrimeFamily::SophieGermain.contains(11));
+    /// assert!(PrimeFamily::Safe.contains(23));
+    /// assert!(PrimeFamily::Emirp.contains(13));
+    /// assert!(!PrimeFamily::Emirp.contains(11));
+    /// assert!(PrimeFamily::Circular.contains(197));
+    /// assert!(!PrimeFamily::Palindromic.contains(121));
+    /// # }
+    /// ```
+    pub fn contains(self, p: usize) -> bool {
+        baillie_psw(p) && self.contains_prime(p)
+    }
+
+    /// Checks whether a prime `p` is in the family.
+    fn contains_prime(self, p: usize) -> bool {
+        match self {
+            Self::SophieGermain => p
+                .checked_mul(2)
+                .and_then(|n| n.checked_add(1))
+                .is_some_and(baillie_psw),
+            Self::Safe => baillie_psw(p / 2),
+            Self::Chen => p
+                .checked_add(2)
+                .is_some_and(|n| baillie_psw(n) || factorize(n as u64).len() == 2),
+            Self::Palindromic => p.is_palindrome(10),
+            Self::Emirp => p
+                .checked_reverse_digits(10)
+                .is_some_and(|q| q != p && baillie_psw(q)),
+            Self::Circular => {
+                let mut digits = p.to_digits(10);
+                (1..digits.len()).all(|_| {
+                    digits.rotate_left(1);
+                    usize::from_digits(&digits, 10).is_some_and(baillie_psw)
+                })
+            }
+        }
+    }
+}
+
+/// A lazy iterator over the primes of a `PrimeFamily` in a range, filtering the output of a
+/// segmented sieve.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::{PrimeFamily, SpecialPrimes};
+/// # fn main() {
+/// assert_eq!(
+///     SpecialPrimes::new(PrimeFamily::SophieGermain, 0..60).collect::<Vec<_>>(),
+///     vec![2, 3, 5, 11, 23, 29, 41, 53]
+/// );
+/// assert_eq!(
+///     SpecialPrimes::new(PrimeFamily::Safe, 0..100).collect::<Vec<_>>(),
+///     vec![5, 7, 11, 23, 47, 59, 83]
+/// );
+/// assert_eq!(
+///     SpecialPrimes::new(PrimeFamily::Palindromic, 100..200).collect::<Vec<_>>(),
+///     vec![101, 131, 151, 181, 191]
+/// );
+/// assert_eq!(
+///     SpecialPrimes::new(PrimeFamily::Emirp, 0..40).collect::<Vec<_>>(),
+///     vec![13, 17, 31, 37]
+/// );
+/// assert_eq!(
+///     SpecialPrimes::new(PrimeFamily::Circular, 0..100).collect::<Vec<_>>(),
+///     vec![2, 3, 5, 7, 11, 13, 17, 31, 37, 71, 73, 79, 97]
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SpecialPrimes {
+    family: PrimeFamily,
+    primes: PrimeTuples,
+}
+
+impl SpecialPrimes {
+    /// Creates a new iterator over the primes of `family` in `range`.
+    pub fn new(family: PrimeFamily, range: Range<usize>) -> Self {
+        Self {
+            family,
+            primes: PrimeTuples::new(&[0], range).expect("a single prime is admissible"),
+        }
+    }
+}
+
+impl Iterator for SpecialPrimes {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.primes
+            .by_ref()
+            .map(|tuple| tuple[0])
+            .find(|&p| self.family.contains_prime(p))
+    }
+}
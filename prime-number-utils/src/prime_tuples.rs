@@ -0,0 +1,205 @@
+use crate::{BitwiseSieve, Error, GenPrime};
+use std::{collections::VecDeque, ops::Range};
+
+/// Minimum number of candidates sieved at once by `PrimeTuples`.
+const MIN_SEGMENT: usize = 1 << 15;
+
+/// Checks whether a pattern of offsets is admissible, that is, for every prime `q` the offsets miss
+/// at least one residue class modulo `q`, so the pattern is not ruled out for large primes.
+///
+/// Only primes up to the number of offsets need to be checked, since larger primes always have a
+/// residue class left.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::is_admissible;
+/// # fn main() {
+/// assert!(is_admissible(&[0, 2, 6, 8]));
+/// assert!(!is_admissible(&[0, 2, 4]));
+/// # }
+/// ```
+pub fn is_admissible(offsets: &[usize]) -> bool {
+    BitwiseSieve::new()
+        .gen_range(0..offsets.len() + 1)
+        .into_iter()
+        .all(|q| {
+            let mut covered = vec![false; q];
+            for offset in offsets {
+                covered[offset % q] = true;
+            }
+            covered.contains(&false)
+        })
+}
+
+/// An iterator over the prime k-tuples `[p + offset for offset in offsets]` whose entries are all
+/// prime, in ascending order of the least prime `p` in a range, using a segmented sieve.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::PrimeTuples;
+/// # fn main() {
+/// let quadruplets = PrimeTuples::new(&[0, 2, 6, 8], 0..200).unwrap();
+/// assert_eq!(
+///     quadruplets.collect::<Vec<_>>(),
+///     vec![
+///         vec![5, 7, 11, 13],
+///         vec![11, 13, 17, 19],
+///         vec![101, 103, 107, 109],
+///         vec![191, 193, 197, 199]
+///     ]
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PrimeTuples {
+    offsets: Vec<usize>,
+    primes: Vec<usize>,
+    start: usize,
+    end: usize,
+    found: VecDeque<Vec<usize>>,
+}
+
+impl PrimeTuples {
+    /// Creates a new iterator over the prime k-tuples with the given offsets whose least prime lies
+    /// in `range`, returning an error if the offsets do not start at zero, are not strictly
+    /// increasing or are not admissible.
+    ///
+    /// # Examples
+    /// ```
+    /// # use prime_number_utils::{Error, PrimeTuples};
+    /// # fn main() {
+    /// assert_eq!(PrimeTuples::new(&[0, 4, 6], 0..100).unwrap().count(), 5);
+    /// assert_eq!(PrimeTuples::new(&[0, 2, 4], 0..100), Err(Error::InvalidInput));
+    /// assert_eq!(PrimeTuples::new(&[2, 6], 0..100), Err(Error::InvalidInput));
+    /// # }
+    /// ```
+    pub fn new(offsets: &[usize], range: Range<usize>) -> Result<Self, Error> {
+        if offsets.first() != Some(&0)
+            || offsets.windows(2).any(|w| w[0] >= w[1])
+            || !is_admissible(offsets)
+        {
+            return Err(Error::InvalidInput);
+        }
+        let width = offsets[offsets.len() - 1];
+        let max = range.end.checked_add(width).ok_or(Error::Overflow)?;
+        let primes = BitwiseSieve::new().gen_range(0..(max as f64).sqrt() as usize + 2);
+        Ok(Self {
+            offsets: offsets.to_vec(),
+            primes,
+            start: range.start,
+            end: range.end,
+            found: VecDeque::new(),
+        })
+    }
+
+    /// Creates a new iterator over the twin primes `(p, p + 2)` with `p` in `range`.
+    ///
+    /// # Panics
+    /// Panics if `range.end + 2` overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use prime_number_utils::PrimeTuples;
+    /// # fn main() {
+    /// assert_eq!(
+    ///     PrimeTuples::twin(0..20).collect::<Vec<_>>(),
+    ///     vec![vec![3, 5], vec![5, 7], vec![11, 13], vec![17, 19]]
+    /// );
+    /// # }
+    /// ```
+    pub fn twin(range: Range<usize>) -> Self {
+        Self::new(&[0, 2], range).expect("attempt to sieve twin primes with overflow")
+    }
+
+    /// Creates a new iterator over the cousin primes `(p, p + 4)` with `p` in `range`.
+    ///
+    /// # Panics
+    /// Panics if `range.end + 4` overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use prime_number_utils::PrimeTuples;
+    /// # fn main() {
+    /// assert_eq!(
+    ///     PrimeTuples::cousin(0..20).collect::<Vec<_>>(),
+    ///     vec![vec![3, 7], vec![7, 11], vec![13, 17], vec![19, 23]]
+    /// );
+    /// # }
+    /// ```
+    pub fn cousin(range: Range<usize>) -> Self {
+        Self::new(&[0, 4], range).expect("attempt to sieve cousin primes with overflow")
+    }
+
+    /// Creates a new iterator over the sexy primes `(p, p + 6)` with `p` in `range`.
+    ///
+    /// # Panics
+    /// Panics if `range.end + 6` overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use prime_number_utils::PrimeTuples;
+    /// # fn main() {
+    /// assert_eq!(
+    ///     PrimeTuples::sexy(0..20).collect::<Vec<_>>(),
+    ///     vec![vec![5, 11], vec![7, 13], vec![11, 17], vec![13, 19], vec![17, 23]]
+    /// );
+    /// # }
+    /// ```
+    pub fn sexy(range: Range<usize>) -> Self {
+        Self::new(&[0, 6], range).expect("attempt to sieve sexy primes with overflow")
+    }
+
+    /// Sieves the next segment of candidates for the least prime and queues the tuples found.
+    fn sieve_segment(&mut self) {
+        let width = self.offsets[self.offsets.len() - 1];
+        let segment = MIN_SEGMENT.max(self.primes.len());
+        let (low, high) = (self.start, self.end.min(self.start.saturating_add(segment)));
+        // is_prime[i] tells whether low + i is prime, for the whole window of the tuples.
+        let mut is_prime = vec![true; high - low + width];
+        for i in low..2.min(high + width) {
+            is_prime[i - low] = false;
+        }
+        for &q in &self.primes {
+            // Near usize::MAX the first multiple may overflow, in which case it is past the window.
+            let first = match (q.checked_mul(q), low.div_ceil(q).checked_mul(q)) {
+                (Some(square), Some(multiple)) => square.max(multiple),
+                _ => continue,
+            };
+            for j in (first..high + width).step_by(q) {
+                is_prime[j - low] = false;
+            }
+        }
+        for p in low..high {
+            if self.offsets.iter().all(|offset| is_prime[p - low + offset]) {
+                self.found
+                    .push_back(self.offsets.iter().map(|offset| p + offset).collect());
+            }
+        }
+        self.start = high;
+    }
+}
+
+impl Iterator for PrimeTuples {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.found.is_empty() && self.start < self.end {
+            self.sieve_segment();
+        }
+        self.found.pop_front()
+    }
+}
+
+/// Counts the twin primes `(p, p + 2)` with `p <= x`.
+///
+/// # Examples
+/// ```
+/// # use prime_number_utils::pi_2;
+/// # fn main() {
+/// assert_eq!(pi_2(1_000_000), 8_169);
+/// # }
+/// ```
+pub fn pi_2(x: usize) -> usize {
+    // `usize::MAX` is a multiple of 3, so no twin prime pair starts above `usize::MAX - 3`.
+    PrimeTuples::twin(0..x.min(usize::MAX - 3) + 1).count()
+}
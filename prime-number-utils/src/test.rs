@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::*;
+    use number_utils::NumberUtils;
 
     #[test]
     fn test_sieve_of_eratosthenes() {
@@ -68,4 +69,618 @@ mod tests {
             vec![2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127, 521, 607]
         );
     }
+
+    #[test]
+    fn test_try_lucas_lehmer() {
+        for i in 0..200 {
+            assert_eq!(try_lucas_lehmer(i).ok(), lucas_lehmer(i));
+        }
+        assert_eq!(try_lucas_lehmer(1 << 40), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn test_proth_test() {
+        use num_bigint::BigUint;
+        for n in 1..300 {
+            for k in (1..200).step_by(2) {
+                if n < 8 && k >> n != 0 {
+                    assert_eq!(proth_test(k, n), None);
+                    continue;
+                }
+                let proth = (BigUint::from(k) << n) + 1_u32;
+                assert_eq!(proth_test(k, n), Some(baillie_psw_big(&proth)));
+            }
+            assert_eq!(proth_test(2, n), None);
+        }
+        // Multipliers above u32::MAX are reduced with a BigUint division.
+        for n in [40, 64, 150] {
+            for k in (u32::MAX as usize + 2..u32::MAX as usize + 200).step_by(2) {
+                let proth = (BigUint::from(k) << n) + 1_u32;
+                assert_eq!(proth_test(k, n), Some(baillie_psw_big(&proth)));
+            }
+        }
+        assert_eq!(proth_test(1, 0), None);
+        assert_eq!(try_proth_test(3, 2), Ok(true));
+        assert_eq!(try_proth_test(5, 2), Err(Error::InvalidInput));
+    }
+
+    #[test]
+    fn test_pepin_test() {
+        let fermat_primes: Vec<usize> = (0..12).filter(|&n| pepin_test(n).unwrap()).collect();
+        assert_eq!(fermat_primes, vec![0, 1, 2, 3, 4]);
+        assert_eq!(pepin_test(usize::BITS as usize), None);
+        assert_eq!(try_pepin_test(4), Ok(true));
+    }
+
+    #[test]
+    fn test_lucas_lehmer_riesel() {
+        use num_bigint::BigUint;
+        for n in 2..300 {
+            for k in (1..200).step_by(2) {
+                if n < 8 && k >> n != 0 {
+                    assert_eq!(lucas_lehmer_riesel(k, n), None);
+                    continue;
+                }
+                let riesel = (BigUint::from(k) << n) - 1_u32;
+                assert_eq!(lucas_lehmer_riesel(k, n), Some(baillie_psw_big(&riesel)));
+            }
+            assert_eq!(lucas_lehmer_riesel(1, n), lucas_lehmer(n));
+            assert_eq!(lucas_lehmer_riesel(2, n), None);
+        }
+        for n in [40, 64, 150] {
+            for k in (u32::MAX as usize + 2..u32::MAX as usize + 200).step_by(2) {
+                let riesel = (BigUint::from(k) << n) - 1_u32;
+                assert_eq!(lucas_lehmer_riesel(k, n), Some(baillie_psw_big(&riesel)));
+            }
+        }
+        assert_eq!(lucas_lehmer_riesel(1, 1), None);
+        assert_eq!(try_lucas_lehmer_riesel(3, 2), Ok(true));
+        assert_eq!(try_lucas_lehmer_riesel(5, 2), Err(Error::InvalidInput));
+    }
+
+    #[test]
+    fn test_baillie_psw_big() {
+        use num_bigint::BigUint;
+        for i in (0..100_000).chain(u64::MAX as usize - 1_000..=u64::MAX as usize) {
+            assert_eq!(baillie_psw_big(&BigUint::from(i)), baillie_psw(i));
+        }
+        for i in 0..700 {
+            let mersenne = (BigUint::from(1_u32) << i) - 1_u32;
+            assert_eq!(baillie_psw_big(&mersenne), lucas_lehmer(i).unwrap());
+        }
+        let p = (BigUint::from(1_u32) << 127) - 1_u32;
+        let q = (BigUint::from(1_u32) << 89) - 1_u32;
+        assert!(!baillie_psw_big(&(&p * &q)));
+        assert!(!baillie_psw_big(&(&p * &p)));
+        assert!(!baillie_psw_big(&((BigUint::from(1_u32) << 128) + 1_u32)));
+        // Strong pseudoprimes to many bases, which Baillie-PSW has to reject.
+        for n in ["3825123056546413051", "318665857834031151167461"] {
+            assert!(!baillie_psw_big(&n.parse().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_random_prime() {
+        use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+        for bits in 2..=64 {
+            for _ in 0..10 {
+                let p = random_prime(bits, &mut rng).unwrap();
+                assert_eq!(p.bits(), bits);
+                assert!(baillie_psw(p.try_into().unwrap()));
+            }
+        }
+        for bits in [128, 512] {
+            let p = random_prime(bits, &mut rng).unwrap();
+            assert_eq!(p.bits(), bits);
+            assert!(baillie_psw_big(&p));
+        }
+        for bits in 3..=40 {
+            let p: usize = random_safe_prime(bits, &mut rng)
+                .unwrap()
+                .try_into()
+                .unwrap();
+            assert_eq!(p.ilog2() + 1, bits as u32);
+            assert!(baillie_psw(p) && baillie_psw(p / 2));
+        }
+        let seeded = |seed| {
+            let mut rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+            (
+                random_prime(256, &mut rng).unwrap(),
+                random_safe_prime(64, &mut rng).unwrap(),
+            )
+        };
+        assert_eq!(seeded(1), seeded(1));
+        assert_ne!(seeded(1), seeded(2));
+        assert_eq!(random_prime(1, &mut rng), None);
+        assert_eq!(try_random_safe_prime(2, &mut rng), Err(Error::InvalidInput));
+    }
+
+    #[test]
+    fn test_prove_prime() {
+        use num_bigint::BigUint;
+        for n in 0..100_000_u64 {
+            let certificate = prove_prime(&BigUint::from(n));
+            assert_eq!(certificate.is_some(), baillie_psw(n as usize));
+            assert!(certificate.is_none_or(|certificate| verify_certificate(&certificate)));
+        }
+        // Certificates for every divisor F of n - 1 are only accepted for primes.
+        for n in 3..1_000_u64 {
+            let prime = baillie_psw(n as usize);
+            let pratt = |witness: u64| Certificate::Pratt {
+                n: BigUint::from(n),
+                witness: BigUint::from(witness),
+                factors: crate::factorize::prime_powers(n - 1)
+                    .into_iter()
+                    .map(|(q, e)| (Certificate::Small(q), e))
+                    .collect(),
+            };
+            assert_eq!((2..n).any(|a| verify_certificate(&pratt(a))), prime);
+            for f in divisors(n - 1) {
+                let factors: Vec<WitnessedFactor> = crate::factorize::prime_powers(f)
+                    .into_iter()
+                    .map(|(q, exponent)| {
+                        let witness = (2..n)
+                            .find(|&a| {
+                                mod_pow(a, n - 1, n) == 1
+                                    && (mod_pow(a, (n - 1) / q, n) + n - 1).gcd(n) == 1
+                            })
+                            .unwrap_or(0);
+                        WitnessedFactor {
+                            prime: Certificate::Small(q),
+                            exponent,
+                            witness: BigUint::from(witness),
+                        }
+                    })
+                    .collect();
+                let n = BigUint::from(n);
+                for certificate in [
+                    Certificate::Pocklington {
+                        n: n.clone(),
+                        factors: factors.clone(),
+                    },
+                    Certificate::Bls { n, factors },
+                ] {
+                    assert!(!verify_certificate(&certificate) || prime);
+                }
+            }
+        }
+        // 2^64 - 59 is the largest prime below 2^64.
+        assert!(verify_certificate(&Certificate::Small(u64::MAX - 58)));
+        assert!(!verify_certificate(&Certificate::Small(u64::MAX)));
+        assert_eq!(
+            prove_prime(&BigUint::from(u64::MAX - 58)),
+            Some(Certificate::Small(u64::MAX - 58))
+        );
+        let one = BigUint::from(1_u32);
+        for n in [
+            (&one << 89) - 1_u32,
+            (&one << 107) - 1_u32,
+            (&one << 127) - 1_u32,
+            (&one << 521) - 1_u32,
+            (BigUint::from(45_u32) << 200) + 1_u32,
+            (BigUint::from(85_u32) << 200) + 1_u32,
+        ] {
+            let certificate = prove_prime(&n).unwrap();
+            assert_eq!(certificate.n(), n);
+            assert!(verify_certificate(&certificate));
+        }
+        assert_eq!(
+            prove_prime(&(((&one << 127) - 1_u32) * ((&one << 89) - 1_u32))),
+            None
+        );
+        assert_eq!(prove_prime(&((&one << 128) + 1_u32)), None);
+
+        use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(0);
+        for _ in 0..20 {
+            // Every composite cofactor of n - 1 has a prime factor small enough for Pollard's rho.
+            let p = random_prime(72, &mut rng).unwrap();
+            let certificate = prove_prime(&p).unwrap();
+            assert!(verify_certificate(&certificate));
+            // The same factors and witnesses do not prove anything about another number.
+            let forged = match certificate {
+                Certificate::Small(_) => unreachable!(),
+                Certificate::Pratt {
+                    n,
+                    witness,
+                    factors,
+                } => Certificate::Pratt {
+                    n: n + 2_u32,
+                    witness,
+                    factors,
+                },
+                Certificate::Pocklington { n, factors } => Certificate::Pocklington {
+                    n: n + 2_u32,
+                    factors,
+                },
+                Certificate::Bls { n, factors } => Certificate::Bls {
+                    n: n + 2_u32,
+                    factors,
+                },
+            };
+            assert!(!verify_certificate(&forged));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_certificate_serde() {
+        use num_bigint::BigUint;
+        let n = (BigUint::from(1_u32) << 127) - 1_u32;
+        let certificate = prove_prime(&n).unwrap();
+        let json = serde_json::to_string(&certificate).unwrap();
+        let deserialized: Certificate = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, certificate);
+        assert!(verify_certificate(&deserialized));
+    }
+
+    #[test]
+    fn test_factorize() {
+        for n in 1..10_000 {
+            assert_eq!(
+                factorize(n),
+                LinearSieve::prime_factorization(n as usize)
+                    .into_iter()
+                    .map(|p| p as u64)
+                    .collect::<Vec<_>>()
+            );
+        }
+        assert_eq!(factorize(0), Vec::<u64>::new());
+        assert_eq!(factorize(1 << 63), vec![2; 63]);
+        assert_eq!(
+            factorize(4_294_967_291 * 4_294_967_279),
+            vec![4_294_967_279, 4_294_967_291]
+        );
+        assert_eq!(
+            factorize(u64::MAX),
+            vec![3, 5, 17, 257, 641, 65_537, 6_700_417]
+        );
+        assert_eq!(
+            factorize(18_446_744_073_709_551_557),
+            vec![18_446_744_073_709_551_557]
+        );
+        let mut n: u64 = 1;
+        for _ in 0..1000 {
+            n = n.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            let prime_factors = factorize(n);
+            assert_eq!(prime_factors.iter().product::<u64>(), n);
+            assert!(prime_factors.iter().all(|&p| baillie_psw(p as usize)));
+        }
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        let moduli = [
+            1,
+            2,
+            3,
+            10,
+            97,
+            1 << 32,
+            (1 << 61) - 1,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        for m in moduli {
+            for base in [0, 1, 2, 12_345, u64::MAX] {
+                for exp in [0, 1, 2, 3, 64, 1_000_003, u64::MAX] {
+                    let mut expected = 1 % m as u128;
+                    let (mut b, mut e) = (base as u128 % m as u128, exp);
+                    while e > 0 {
+                        if e & 1 == 1 {
+                            expected = expected * b % m as u128;
+                        }
+                        b = b * b % m as u128;
+                        e >>= 1;
+                    }
+                    assert_eq!(mod_pow(base, exp, m) as u128, expected);
+                }
+            }
+        }
+        for m in 1..200 {
+            for a in 0..m {
+                match mod_inverse(a, m) {
+                    Some(x) => assert_eq!(a * x % m, 1 % m),
+                    None => assert!((0..m).all(|x| a * x % m != 1 % m)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_sqrt() {
+        for m in 1..400 {
+            for a in 0..m {
+                match mod_sqrt(a, m) {
+                    Some(x) => {
+                        assert!(x <= m - x);
+                        assert_eq!(x * x % m, a);
+                    }
+                    None => assert!((0..m).all(|x| x * x % m != a)),
+                }
+            }
+        }
+        // 998_244_353 - 1 and 469_762_049 - 1 are divisible by 2^23 and 2^26, so they use Cipolla.
+        for p in [1_000_000_007, 998_244_353, (1 << 61) - 1, 469_762_049] {
+            for a in [2, 3, 5, 123_456_789] {
+                let x = mod_sqrt(a, p);
+                assert_eq!(x.is_some(), mod_pow(a, (p - 1) / 2, p) == 1);
+                assert!(x.is_none_or(|x| mod_pow(x, 2, p) == a));
+            }
+        }
+        let m = 1 << 40;
+        assert_eq!(mod_pow(mod_sqrt(17, m).unwrap(), 2, m), 17);
+        let m = 3_u64.pow(40);
+        assert_eq!(
+            mod_pow(mod_sqrt(3_u64.pow(10) * 7, m).unwrap(), 2, m),
+            3_u64.pow(10) * 7
+        );
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        for m in 1..100 {
+            for g in 0..m {
+                let mut powers = vec![];
+                let mut x = 1 % m;
+                for _ in 0..2 * m {
+                    powers.push(x);
+                    x = x * g % m;
+                }
+                for h in 0..m {
+                    let expected = powers.iter().position(|&y| y == h).map(|i| i as u64);
+                    assert_eq!(discrete_log(g, h, m), expected);
+                }
+            }
+        }
+        let p = 1_000_000_007;
+        let x = discrete_log(5, 123_456_789, p).unwrap();
+        assert_eq!(mod_pow(5, x, p), 123_456_789);
+        let m = 1 << 50;
+        assert_eq!(
+            discrete_log(3, mod_pow(3, 123_456_789_012, m), m),
+            Some(123_456_789_012)
+        );
+    }
+
+    #[test]
+    fn test_primitive_root() {
+        for m in 1..300_u64 {
+            let units: Vec<u64> = (1..=m).filter(|&a| a.gcd(m) == 1).map(|a| a % m).collect();
+            let expected = (0..m).find(|&g| {
+                units.contains(&g) && {
+                    let mut seen: Vec<u64> = (0..units.len() as u32)
+                        .map(|k| mod_pow(g, k as u64, m))
+                        .collect();
+                    seen.sort();
+                    seen.dedup();
+                    seen.len() == units.len()
+                }
+            });
+            assert_eq!(primitive_root(m), expected);
+        }
+        assert_eq!(primitive_root(998_244_353), Some(3));
+        assert_eq!(primitive_root(2 * 3_u64.pow(30)), Some(5));
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        for n in 1..300_u64 {
+            let units: Vec<u64> = (0..n).filter(|&a| a.gcd(n) == 1).collect();
+            let orders: Vec<u64> = units
+                .iter()
+                .map(|&a| (1..=n).find(|&k| mod_pow(a, k, n) == 1 % n).unwrap())
+                .collect();
+            assert_eq!(euler_phi(n), units.len() as u64);
+            assert_eq!(carmichael_lambda(n), orders.iter().copied().max().unwrap());
+            for a in 0..n {
+                let expected = units.iter().position(|&u| u == a).map(|i| orders[i]);
+                assert_eq!(multiplicative_order(a, n), expected);
+            }
+        }
+        assert_eq!(euler_phi(0), 0);
+        assert_eq!(carmichael_lambda(0), 0);
+        assert_eq!(multiplicative_order(1, 0), None);
+        assert_eq!(euler_phi(u64::MAX), 9_208_981_628_670_443_520);
+        let p = (1 << 61) - 1;
+        assert_eq!(multiplicative_order(2, p), Some(61));
+        assert_eq!(multiplicative_order(37, p), Some(p - 1));
+        assert_eq!(primitive_root(p), Some(37));
+        assert_eq!(carmichael_lambda(u64::MAX), 17_153_064_960);
+    }
+
+    #[test]
+    fn test_divisors() {
+        for n in 1..3_000_u64 {
+            let expected: Vec<u64> = (1..=n).filter(|d| n % d == 0).collect();
+            assert_eq!(divisors(n), expected);
+            assert_eq!(divisor_count(n), expected.len() as u64);
+            for k in 0..4 {
+                assert_eq!(
+                    divisor_sigma(n, k),
+                    Some(expected.iter().map(|d| d.pow(k)).sum())
+                );
+            }
+            let s = proper_divisor_sum(n).unwrap();
+            assert_eq!(is_perfect(n), s == n);
+            assert_eq!(is_abundant(n), s > n);
+            assert_eq!(is_deficient(n), s < n);
+        }
+        assert_eq!(divisor_count(963_761_198_400), 6_720);
+        assert_eq!(divisors(963_761_198_400).len(), 6_720);
+        assert_eq!(divisor_sigma(0, 1), Some(0));
+        assert_eq!(try_divisor_sigma(1 << 32, 2), Err(Error::Overflow));
+        assert!(!is_perfect(0) && !is_abundant(0) && !is_deficient(0));
+        assert!(is_abundant(u64::MAX - 15));
+
+        let pairs = amicable_pairs(100_000);
+        assert_eq!(pairs.len(), 13);
+        for (a, b) in pairs {
+            assert_eq!(amicable_partner(a as u64), Some(b as u64));
+            assert_eq!(amicable_partner(b as u64), Some(a as u64));
+        }
+        assert_eq!(amicable_partner(0), None);
+        assert_eq!(amicable_partner(1), None);
+        assert_eq!(amicable_partner(496), None);
+    }
+
+    #[test]
+    fn test_arithmetic_progression_sieve() {
+        let primes = SieveOfEratosthenes::new().gen_range(0..200_000);
+        for m in 1..40 {
+            for a in 0..m {
+                for range in [0..200_000, 0..2, 2..3, 1_000..1_009, 12_345..123_456] {
+                    let expected: Vec<usize> = primes
+                        .iter()
+                        .copied()
+                        .filter(|p| p % m == a && range.contains(p))
+                        .collect();
+                    let mut sieve = ArithmeticProgressionSieve::new(a, m);
+                    assert_eq!(sieve.gen_range(range), expected);
+                }
+            }
+        }
+        let m = 1_000_003;
+        let expected: Vec<usize> = (0..2_000)
+            .map(|k| 10_000_000_000 + 12 + k * m)
+            .filter(|&n| baillie_psw(n))
+            .collect();
+        let mut sieve = ArithmeticProgressionSieve::new(10_000_000_012, m);
+        assert_eq!(
+            sieve.gen_range(10_000_000_000..10_000_000_000 + 2_000 * m),
+            expected
+        );
+        assert_eq!(prime_pi_mod(10_000_000, 1, 4), 332_180);
+        assert_eq!(prime_pi_mod(10_000_000, 3, 4), 332_398);
+        assert_eq!(prime_pi_mod(usize::MAX, 0, 1 << 62), 0);
+    }
+
+    #[test]
+    fn test_prime_tuples() {
+        let mut is_prime = vec![false; 300_100];
+        for p in SieveOfEratosthenes::new().gen_range(0..300_100) {
+            is_prime[p] = true;
+        }
+        let patterns: [&[usize]; 6] = [
+            &[0, 2],
+            &[0, 4],
+            &[0, 6],
+            &[0, 2, 6],
+            &[0, 4, 6],
+            &[0, 2, 6, 8, 12],
+        ];
+        for offsets in patterns {
+            for range in [0..300_000, 0..3, 5..5, 99_990..200_017] {
+                let expected: Vec<Vec<usize>> = range
+                    .clone()
+                    .filter(|p| offsets.iter().all(|o| is_prime[p + o]))
+                    .map(|p| offsets.iter().map(|o| p + o).collect())
+                    .collect();
+                let tuples = PrimeTuples::new(offsets, range).unwrap();
+                assert_eq!(tuples.collect::<Vec<_>>(), expected);
+            }
+        }
+        assert_eq!(PrimeTuples::new(&[], 0..10), Err(Error::InvalidInput));
+        assert_eq!(
+            PrimeTuples::new(&[0, 2, 2], 0..10),
+            Err(Error::InvalidInput)
+        );
+        assert_eq!(
+            PrimeTuples::new(&[0, 2, 6, 8, 12, 14], 0..10),
+            Err(Error::InvalidInput)
+        );
+        assert_eq!(
+            PrimeTuples::new(&[0, 2], 0..usize::MAX),
+            Err(Error::Overflow)
+        );
+        assert!(is_admissible(&[0]));
+        assert!(!is_admissible(&[0, 1]));
+        assert_eq!(pi_2(10_000_000), 58_980);
+        assert_eq!(pi_2(0), 0);
+        assert_eq!(
+            PrimeTuples::twin(1_000_000_000_000..1_000_000_000_100).next(),
+            Some(vec![1_000_000_000_061, 1_000_000_000_063])
+        );
+    }
+
+    #[test]
+    fn test_sieve_near_usize_max() {
+        // Windows ending just below usize::MAX - width, where the multiples of the sieving primes
+        // overflow.
+        let tuples = PrimeTuples::new(&[0, 2], usize::MAX - 1000..usize::MAX - 2).unwrap();
+        let expected: Vec<_> = (usize::MAX - 1000..usize::MAX - 2)
+            .filter(|&p| baillie_psw(p) && baillie_psw(p + 2))
+            .map(|p| vec![p, p + 2])
+            .collect();
+        assert_eq!(tuples.collect::<Vec<_>>(), expected);
+        let range = usize::MAX - 1000..usize::MAX - 10;
+        let expected: Vec<_> = range
+            .clone()
+            .filter(|&p| PrimeFamily::Chen.contains(p))
+            .collect();
+        assert!(!expected.is_empty());
+        assert_eq!(
+            SpecialPrimes::new(PrimeFamily::Chen, range).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_special_primes() {
+        let primes = SieveOfEratosthenes::new().gen_range(0..3_000_000);
+        let is_prime = |n: usize| primes.binary_search(&n).is_ok();
+        let families = [
+            PrimeFamily::SophieGermain,
+            PrimeFamily::Safe,
+            PrimeFamily::Chen,
+            PrimeFamily::Palindromic,
+            PrimeFamily::Emirp,
+            PrimeFamily::Circular,
+        ];
+        for family in families {
+            let expected: Vec<usize> = primes
+                .iter()
+                .copied()
+                .take_while(|&p| p < 1_000_000)
+                .filter(|&p| {
+                    let digits = p.to_string();
+                    let reversed: String = digits.chars().rev().collect();
+                    match family {
+                        PrimeFamily::SophieGermain => is_prime(2 * p + 1),
+                        PrimeFamily::Safe => is_prime((p - 1) / 2),
+                        PrimeFamily::Chen => {
+                            is_prime(p + 2)
+                                || primes
+                                    .iter()
+                                    .take_while(|&&q| q * q <= p + 2)
+                                    .any(|&q| (p + 2) % q == 0 && is_prime((p + 2) / q))
+                        }
+                        PrimeFamily::Palindromic => digits == reversed,
+                        PrimeFamily::Emirp => {
+                            digits != reversed && is_prime(reversed.parse().unwrap())
+                        }
+                        PrimeFamily::Circular => (0..digits.len()).all(|i| {
+                            let rotated = format!("{}{}", &digits[i..], &digits[..i]);
+                            is_prime(rotated.parse().unwrap())
+                        }),
+                    }
+                })
+                .collect();
+            let special_primes = SpecialPrimes::new(family, 0..1_000_000);
+            assert_eq!(special_primes.collect::<Vec<_>>(), expected);
+            for p in 0..1_000 {
+                assert_eq!(family.contains(p), expected.contains(&p));
+            }
+        }
+        assert_eq!(
+            SpecialPrimes::new(PrimeFamily::Circular, 0..1_000_000).count(),
+            55
+        );
+        assert_eq!(
+            SpecialPrimes::new(PrimeFamily::Safe, 1_000_000_000_000..1_000_000_001_000).next(),
+            Some(1_000_000_000_547)
+        );
+        assert!(!PrimeFamily::SophieGermain.contains(usize::MAX / 2 + 1));
+    }
 }